@@ -0,0 +1,170 @@
+use std::iter::{self, Sum};
+use std::ops::Mul;
+use super::{NDBox, NDIntoIterator, NDSlice};
+
+/// Which part of the full convolution to compute, following numpy's conventions.
+/// Along each dimension, the input has length I and the kernel has length K.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvolveMode {
+  /// Every position where the kernel overlaps the input (length I + K - 1)
+  Full,
+  /// The center of the full output with the same length as the input (length I)
+  Same,
+  /// Only the positions where the kernel lies entirely inside the input (length I - K + 1)
+  Valid,
+}
+
+impl ConvolveMode {
+  /// Computes which indices of the full output to compute along a dimension,
+  /// returning the first index and the number of indices
+  fn output_range(self, input_len: usize, kernel_len: usize) -> (usize, usize) {
+    match self {
+      Self::Full => (0, input_len + kernel_len - 1),
+      Self::Same => ((kernel_len - 1) / 2, input_len),
+      Self::Valid => (kernel_len - 1, (input_len + 1).saturating_sub(kernel_len)),
+    }
+  }
+}
+
+/// Computes a convolution (if `convolution` is true) or correlation of `input` and `kernel`.
+/// Index f of the full convolution is the sum of input[f - k] * kernel[k],
+/// and index f of the full correlation is the sum of input[f - (K - 1 - k)] * kernel[k].
+fn filter<'a, 'b, T, const N: usize>(
+  input: NDSlice<'a, T, N>,
+  kernel: NDSlice<'b, T, N>,
+  mode: ConvolveMode,
+  convolution: bool,
+) -> NDBox<T, N> where
+  T: Sum,
+  for<'x> &'x T: Mul<&'x T, Output = T>,
+{
+  let kernel_len = kernel.len;
+  assert!(
+    kernel_len.size() > 0,
+    "Cannot convolve with an empty kernel of {:?}", kernel_len,
+  );
+  let ranges = input.len.0.zip(kernel_len.0)
+    .map(|(dimension_len, kernel_dimension_len)| {
+      mode.output_range(dimension_len, kernel_dimension_len)
+    });
+  NDBox::new_with(ranges.map(|(_, dimension_len)| dimension_len), |index| {
+    kernel.iter().filter_map(|(kernel_index, kernel_value)| {
+      let mut input_index = index;
+      for (((dimension_index, (dimension_start, _)), kernel_dimension_index), kernel_dimension_len)
+        in iter::zip(&mut input_index, ranges).zip(kernel_index).zip(kernel_len.0)
+      {
+        let kernel_offset =
+          if convolution { kernel_dimension_index }
+          else { kernel_dimension_len - 1 - kernel_dimension_index };
+        *dimension_index = (*dimension_index + dimension_start).checked_sub(kernel_offset)?;
+      }
+      // Skip kernel elements that don't overlap the input
+      input.get(input_index).map(|input_value| input_value * kernel_value)
+    }).sum()
+  })
+}
+
+/// Like filter(), but convolves or correlates along a single dimension with a 1-dimensional kernel
+fn filter_dimension<'a, 'b, T, const N: usize>(
+  input: NDSlice<'a, T, N>,
+  kernel: NDSlice<'b, T, 1>,
+  dimension: usize,
+  mode: ConvolveMode,
+  convolution: bool,
+) -> NDBox<T, N> where
+  T: Sum,
+  for<'x> &'x T: Mul<&'x T, Output = T>,
+{
+  let kernel_len = kernel.len;
+  assert!(
+    kernel_len.size() > 0,
+    "Cannot convolve with an empty kernel of {:?}", kernel_len,
+  );
+  let [kernel_len] = kernel_len.0;
+  let (start, dimension_len) = mode.output_range(input.len.0[dimension], kernel_len);
+  let mut len = input.len.0;
+  len[dimension] = dimension_len;
+  NDBox::new_with(len, |index| {
+    kernel.iter().filter_map(|([kernel_index], kernel_value)| {
+      let kernel_offset = if convolution { kernel_index } else { kernel_len - 1 - kernel_index };
+      let mut input_index = index;
+      input_index[dimension] = (index[dimension] + start).checked_sub(kernel_offset)?;
+      input.get(input_index).map(|input_value| input_value * kernel_value)
+    }).sum()
+  })
+}
+
+/// Applies filter_dimension() along each dimension in turn
+fn filter_separable<'a, 'b, T, const N: usize>(
+  input: NDSlice<'a, T, N>,
+  kernels: [NDSlice<'b, T, 1>; N],
+  mode: ConvolveMode,
+  convolution: bool,
+) -> NDBox<T, N> where
+  T: Clone + Sum,
+  for<'x> &'x T: Mul<&'x T, Output = T>,
+{
+  let mut result: Option<NDBox<T, N>> = None;
+  for (dimension, kernel) in kernels.into_iter().enumerate() {
+    let dimension_input = result.as_ref().map_or(input, NDBox::as_slice);
+    result = Some(filter_dimension(dimension_input, kernel, dimension, mode, convolution));
+  }
+  // A 0-dimensional convolution has no kernels, so the result is the input
+  result.unwrap_or_else(|| input.map(T::clone))
+}
+
+/// Computes the N-dimensional convolution of `input` with `kernel`.
+/// Index f of the full convolution is the sum of input[f - k] * kernel[k]
+/// over all kernel indices k where f - k is in bounds;
+/// `mode` selects which part of the full convolution is returned.
+pub fn convolve<'a, 'b, T, const N: usize>(
+  input: NDSlice<'a, T, N>,
+  kernel: NDSlice<'b, T, N>,
+  mode: ConvolveMode,
+) -> NDBox<T, N> where
+  T: Sum,
+  for<'x> &'x T: Mul<&'x T, Output = T>,
+{
+  filter(input, kernel, mode, true)
+}
+
+/// Computes the N-dimensional cross-correlation of `input` with `kernel`.
+/// This is the convolution with the kernel reversed along each dimension,
+/// i.e. the kernel is slid over the input without being flipped.
+pub fn correlate<'a, 'b, T, const N: usize>(
+  input: NDSlice<'a, T, N>,
+  kernel: NDSlice<'b, T, N>,
+  mode: ConvolveMode,
+) -> NDBox<T, N> where
+  T: Sum,
+  for<'x> &'x T: Mul<&'x T, Output = T>,
+{
+  filter(input, kernel, mode, false)
+}
+
+/// Fast path for convolve() with a separable kernel,
+/// which is the outer product of a 1-dimensional kernel for each dimension.
+/// Convolving with each 1-dimensional kernel in turn takes O(K0 + ... + K(N-1)) time
+/// per element rather than O(K0 * ... * K(N-1)).
+pub fn convolve_separable<'a, 'b, T, const N: usize>(
+  input: NDSlice<'a, T, N>,
+  kernels: [NDSlice<'b, T, 1>; N],
+  mode: ConvolveMode,
+) -> NDBox<T, N> where
+  T: Clone + Sum,
+  for<'x> &'x T: Mul<&'x T, Output = T>,
+{
+  filter_separable(input, kernels, mode, true)
+}
+
+/// Fast path for correlate() with a separable kernel (see convolve_separable())
+pub fn correlate_separable<'a, 'b, T, const N: usize>(
+  input: NDSlice<'a, T, N>,
+  kernels: [NDSlice<'b, T, 1>; N],
+  mode: ConvolveMode,
+) -> NDBox<T, N> where
+  T: Clone + Sum,
+  for<'x> &'x T: Mul<&'x T, Output = T>,
+{
+  filter_separable(input, kernels, mode, false)
+}
@@ -0,0 +1,39 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+
+/// The ways the checked (try_*) operations can fail.
+/// Lengths are stored as Vecs so errors from slices of different dimensions have the same type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NDError {
+  /// The lengths of two slices are incompatible for an operation
+  ShapeMismatch { len1: Vec<usize>, len2: Vec<usize> },
+  /// An index along a dimension is not less than the dimension's length
+  OutOfBounds { dimension: usize, index: usize, dimension_len: usize },
+  /// A range along a dimension is backwards or extends past the dimension's length
+  RangeOutOfBounds { dimension: usize, range: Range<usize>, dimension_len: usize },
+  /// The number of elements in a slice of the given length doesn't fit in a usize
+  SizeOverflow { len: Vec<usize> },
+  /// There isn't enough memory to allocate a box of the given length
+  AllocationFailed { len: Vec<usize> },
+}
+
+impl Display for NDError {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::ShapeMismatch { len1, len2 } => {
+        write!(f, "incompatible lengths {:?} and {:?}", len1, len2)
+      },
+      Self::OutOfBounds { index, dimension_len, .. } => {
+        write!(f, "index {} out of bounds for dimension of len {}", index, dimension_len)
+      },
+      Self::RangeOutOfBounds { range, dimension_len, .. } => {
+        write!(f, "range {:?} out of bounds for dimension of len {}", range, dimension_len)
+      },
+      Self::SizeOverflow { len } => write!(f, "size of {:?} overflows usize", len),
+      Self::AllocationFailed { len } => write!(f, "failed to allocate NDBox of {:?}", len),
+    }
+  }
+}
+
+impl Error for NDError {}
@@ -0,0 +1,84 @@
+use std::borrow::Borrow;
+use std::mem;
+use std::ptr;
+use super::{Index, IndexIterator, Len, NDIntoIterator, NDSlice, NDSliceMut};
+
+impl<'a, T, const N: usize> NDSliceMut<'a, T, N> {
+  /// Sets every element to a clone of `value`, like <[T]>::fill()
+  pub fn fill(&mut self, value: T) where T: Clone {
+    for (_, element) in self.iter_mut() {
+      element.clone_from(&value);
+    }
+  }
+
+  /// Sets every element (in row-major order) to the result of calling `f`,
+  /// like <[T]>::fill_with()
+  pub fn fill_with<F: FnMut() -> T>(&mut self, mut f: F) {
+    for (_, element) in self.iter_mut() {
+      *element = f();
+    }
+  }
+
+  /// Sets the elements to clones of the corresponding values of `from`,
+  /// which must have the same length. `from` can be any N-dimensional slice or box,
+  /// of either elements or references to elements.
+  pub fn assign<I>(&mut self, from: I) where
+    I: NDIntoIterator<N>,
+    I::Item: Borrow<T>,
+    T: Clone,
+  {
+    for (element, value) in self.zip(from) {
+      element.clone_from(value.borrow());
+    }
+  }
+
+  /// Copies the elements of `from`, which must have the same length,
+  /// like <[T]>::copy_from_slice().
+  /// Each contiguous run of elements along the last dimension is copied with a memcpy.
+  pub fn copy_from(&mut self, from: NDSlice<T, N>) where T: Copy {
+    let len = self.len;
+    assert!(
+      len == from.len,
+      "Cannot operate on NDSlices with {:?} and {:?}", len, from.len,
+    );
+    let size = len.size();
+    let last_dimension = match N.checked_sub(1) {
+      Some(last_dimension) if size > 0 => last_dimension,
+      // A 0-dimensional slice has a single element and no runs to copy
+      _ => return self.assign(from),
+    };
+
+    let default_stride = len.default_stride().0;
+    let (run_len, runs_len) =
+      if self.stride.0 == default_stride && from.stride.0 == default_stride {
+        // Both slices are entirely contiguous, so they can be copied in one run
+        (size, Len([1; N]))
+      }
+      else if self.stride.0[last_dimension] == 1 && from.stride.0[last_dimension] == 1 {
+        // Copy each row along the last dimension
+        let mut runs_len = len;
+        runs_len.0[last_dimension] = 1;
+        (len.0[last_dimension], runs_len)
+      }
+      else { return self.assign(from) };
+    let destination = self.as_slice();
+    for index in IndexIterator::new(runs_len).take(runs_len.size()) {
+      // SAFETY: `index` is the start of a run of `run_len` contiguous elements in both slices.
+      // `&mut self` has exclusive access to its elements, so `from` can't view any of them
+      // and the runs can't overlap.
+      unsafe {
+        let source = from.location(Index(index)).as_ptr();
+        let destination = destination.location(Index(index)).as_ptr();
+        ptr::copy_nonoverlapping(source, destination, run_len);
+      }
+    }
+  }
+
+  /// Swaps the elements with the corresponding elements of `other`,
+  /// which must have the same length, like <[T]>::swap_with_slice()
+  pub fn swap_with(&mut self, other: &mut NDSliceMut<T, N>) {
+    for (element, other_element) in self.zip(other) {
+      mem::swap(element, other_element);
+    }
+  }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Debug;
+use std::iter::{Product, Sum};
+use std::ops::*;
+
+/// The floating-point operations needed by numerical algorithms (e.g. expm()).
+/// Implemented for f32 and f64.
+pub trait Float:
+  Copy + Debug + PartialOrd +
+  Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> +
+  Neg<Output = Self> + Sum + Product
+{
+  const ZERO: Self;
+  const ONE: Self;
+  const NAN: Self;
+
+  /// Converts an f64 constant to this type (possibly losing precision)
+  fn from_f64(value: f64) -> Self;
+
+  /// Converts this value to an f64
+  fn to_f64(self) -> f64;
+
+  fn abs(self) -> Self;
+
+  fn sqrt(self) -> Self;
+
+  fn is_nan(self) -> bool;
+
+  fn is_finite(self) -> bool;
+}
+
+macro_rules! float_impl {
+  ($type:ident) => {
+    impl Float for $type {
+      const ZERO: Self = 0.0;
+      const ONE: Self = 1.0;
+      const NAN: Self = $type::NAN;
+
+      fn from_f64(value: f64) -> Self {
+        value as $type
+      }
+
+      fn to_f64(self) -> f64 {
+        self as f64
+      }
+
+      fn abs(self) -> Self {
+        $type::abs(self)
+      }
+
+      fn sqrt(self) -> Self {
+        $type::sqrt(self)
+      }
+
+      fn is_nan(self) -> bool {
+        $type::is_nan(self)
+      }
+
+      fn is_finite(self) -> bool {
+        $type::is_finite(self)
+      }
+    }
+  };
+}
+
+float_impl!{f32}
+float_impl!{f64}
@@ -0,0 +1,105 @@
+use std::ops::AddAssign;
+use super::{Is, Len, NDBox, NDIntoIterator, NDSlice, NDSliceMut, True};
+
+/// Checks that the indices passed to take_along_axis() etc. can be used with a slice:
+/// they must have the same length along all dimensions except `D`
+fn check_along_axis<const N: usize, const D: usize>(len: Len<N>, indices_len: Len<N>) {
+  let mut expected_len = len;
+  expected_len.0[D] = indices_len.0[D];
+  assert!(
+    indices_len == expected_len,
+    "Cannot index {:?} along dimension {} with indices of {:?}", len, D, indices_len,
+  );
+}
+
+/// Checks that an index is in bounds along dimension `D`
+fn check_dimension_index<const N: usize, const D: usize>(len: Len<N>, dimension_index: usize) {
+  let dimension_len = len.0[D];
+  assert!(
+    dimension_index < dimension_len,
+    "index {} out of bounds for dimension of len {}", dimension_index, dimension_len,
+  );
+}
+
+impl<'a, T: Clone, const N: usize> NDSlice<'a, T, N> {
+  /// Copies the given indices along dimension `D` (in the given order, possibly repeated).
+  /// Index i along dimension `D` of the result is index `indices[i]` of the slice.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn select<const D: usize>(self, indices: &[usize]) -> NDBox<T, N>
+    where Is<{D < N}>: True
+  {
+    for &dimension_index in indices {
+      check_dimension_index::<N, D>(self.len, dimension_index);
+    }
+    let mut len = self.len.0;
+    len[D] = indices.len();
+    NDBox::new_with(len, |mut index| {
+      index[D] = indices[index[D]];
+      self.index(index).clone()
+    })
+  }
+
+  /// Copies the values at `indices` along dimension `D`.
+  /// `indices` must have the same length as the slice along every other dimension,
+  /// and the result has the same length as `indices`.
+  /// Index [i0, ..., iD, ..., i(N-1)] of the result is
+  /// index [i0, ..., indices[i0, ..., iD, ..., i(N-1)], ..., i(N-1)] of the slice.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn take_along_axis<const D: usize>(self, indices: NDSlice<usize, N>) -> NDBox<T, N>
+    where Is<{D < N}>: True
+  {
+    check_along_axis::<N, D>(self.len, indices.len);
+    NDBox::new_with(indices.len.0, |mut index| {
+      let dimension_index = *indices.index(index);
+      check_dimension_index::<N, D>(self.len, dimension_index);
+      index[D] = dimension_index;
+      self.index(index).clone()
+    })
+  }
+}
+
+impl<'a, T, const N: usize> NDSliceMut<'a, T, N> {
+  /// The inverse of NDSlice::take_along_axis(): stores each value from `values`
+  /// at the corresponding index from `indices` along dimension `D`.
+  /// `values` must have the same length as `indices`.
+  /// If an index is repeated, the last value stored there is kept.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn put_along_axis<const D: usize, I>(&mut self, indices: NDSlice<usize, N>, values: I)
+    where
+      Is<{D < N}>: True,
+      I: NDIntoIterator<N, Item = T>,
+  {
+    self.scatter::<D, _, _>(indices, values, |element, value| *element = value);
+  }
+
+  /// Adds each value from `values` to the element at the corresponding index
+  /// from `indices` along dimension `D`, e.g. to accumulate values into bins.
+  /// `values` must have the same length as `indices`.
+  /// If an index is repeated, all its values are added.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn scatter_add<const D: usize, I>(&mut self, indices: NDSlice<usize, N>, values: I)
+    where
+      Is<{D < N}>: True,
+      I: NDIntoIterator<N>,
+      T: AddAssign<I::Item>,
+  {
+    self.scatter::<D, _, _>(indices, values, |element, value| *element += value);
+  }
+
+  /// Combines each value from `values` into the element at the corresponding index
+  /// from `indices` along dimension `D`
+  fn scatter<const D: usize, I, F>(&mut self, indices: NDSlice<usize, N>, values: I, mut f: F)
+    where
+      Is<{D < N}>: True,
+      I: NDIntoIterator<N>,
+      F: FnMut(&mut T, I::Item),
+  {
+    let len = self.len;
+    check_along_axis::<N, D>(len, indices.len);
+    for (mut index, (&dimension_index, value)) in indices.indices().zip(indices.zip(values)) {
+      check_dimension_index::<N, D>(len, dimension_index);
+      index[D] = dimension_index;
+      f(self.index_mut(index), value);
+    }
+  }
+}
@@ -0,0 +1,224 @@
+use std::convert::Infallible;
+use std::mem::MaybeUninit;
+use std::slice;
+use super::{try_write_all, Index, IndexIterator, Len, NDBox, NDSlice};
+
+/// The order in which an NDBox stores its elements in memory
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layout {
+  /// Indices are ordered by dimension 0, then 1, ..., N - 1 (C order).
+  /// So consecutive elements along the last dimension are adjacent in memory.
+  RowMajor,
+  /// Indices are ordered by dimension N - 1, then N - 2, ..., 0 (Fortran order).
+  /// So consecutive elements along the first dimension are adjacent in memory.
+  ColumnMajor,
+}
+
+impl<T, const N: usize> NDBox<T, N> {
+  /// Returns the order in which the elements are stored in memory
+  pub fn layout(&self) -> Layout {
+    self.layout
+  }
+
+  /// Like NDBox::new_with(), but stores the elements in the given layout.
+  /// The initializer is called on the indices in memory order.
+  pub fn new_with_in<F>(len: [usize; N], layout: Layout, mut init: F) -> Self
+    where F: FnMut([usize; N]) -> T
+  {
+    let result = Self::try_new_with_in(len, layout, |index| Ok::<_, Infallible>(init(index)));
+    result.unwrap_or_else(|never| match never {})
+  }
+
+  /// Like NDBox::try_new_with(), but stores the elements in the given layout.
+  /// The initializer is called on the indices in memory order.
+  pub fn try_new_with_in<E, F>(len: [usize; N], layout: Layout, init: F) -> Result<Self, E>
+    where F: FnMut([usize; N]) -> Result<T, E>
+  {
+    let len = Len(len);
+    let mut data = Box::new_uninit_slice(len.size());
+    // If `init` fails or panics, the elements already initialized are dropped
+    try_write_all(&mut data, len.layout_indices(layout).map(init))?;
+    // SAFETY: `data` has length `size(len)` and all elements were written to, in memory order
+    Ok(unsafe { Self::from_slice_in_unchecked(len, layout, data.assume_init()) })
+  }
+
+  /// Like NDBox::new_fill(), but stores the elements in the given layout
+  pub fn new_fill_in(len: [usize; N], layout: Layout, value: T) -> Self where T: Clone {
+    Self::new_with_in(len, layout, |_| value.clone())
+  }
+
+  /// Like NDBox::new_default(), but stores the elements in the given layout
+  pub fn new_default_in(len: [usize; N], layout: Layout) -> Self where T: Default {
+    Self::new_with_in(len, layout, |_| T::default())
+  }
+
+  /// Like NDBox::new_uninit(), but stores the elements in the given layout
+  pub fn new_uninit_in(len: [usize; N], layout: Layout) -> NDBox<MaybeUninit<T>, N> {
+    let len = Len(len);
+    let data = Box::new_uninit_slice(len.size());
+    // SAFETY: `data` has length `size(len)`
+    unsafe { NDBox::from_slice_in_unchecked(len, layout, data) }
+  }
+
+  /// Creates an NDBox from its elements in memory order for the given layout,
+  /// e.g. a matrix from column-major numerical code, without copying them.
+  /// Panics if the number of elements doesn't match `len`.
+  pub fn from_raw_vec(len: [usize; N], layout: Layout, data: Vec<T>) -> Self {
+    let len = Len(len);
+    let size = len.size();
+    assert!(
+      data.len() == size,
+      "Cannot create NDBox of {:?} from {} elements", len, data.len(),
+    );
+    // SAFETY: `data` has length `size(len)`
+    unsafe { Self::from_slice_in_unchecked(len, layout, data.into_boxed_slice()) }
+  }
+
+  /// Returns the elements in memory order (see NDBox::layout()), without copying them.
+  /// The inverse of NDBox::from_raw_vec().
+  pub fn into_raw_vec(self) -> Vec<T> {
+    self.to_box().into_vec()
+  }
+
+  /// Returns the elements as a 1-dimensional Rust slice in memory order
+  pub fn as_raw_slice(&self) -> &[T] {
+    // SAFETY: the allocation consists of the `size` elements starting at `data`
+    unsafe { slice::from_raw_parts(self.data.as_ptr(), self.len.size()) }
+  }
+
+  /// Returns the elements as a 1-dimensional mutable Rust slice in memory order
+  pub fn as_raw_slice_mut(&mut self) -> &mut [T] {
+    // SAFETY: the allocation consists of the `size` elements starting at `data`,
+    // and `self` is borrowed mutably
+    unsafe { slice::from_raw_parts_mut(self.data.as_ptr(), self.len.size()) }
+  }
+
+  /// Copies the elements into a new NDBox with the given layout
+  pub fn to_layout(&self, layout: Layout) -> Self where T: Clone {
+    self.as_slice().to_owned_in(layout)
+  }
+
+  /// Converts the box to the given layout, moving the elements into a new allocation
+  /// if the layout is different
+  pub fn into_layout(self, layout: Layout) -> Self {
+    let len = self.len;
+    let data = self.into_layout_box(layout);
+    // SAFETY: `data` has length `size(len)`, in memory order for `layout`
+    unsafe { Self::from_slice_in_unchecked(len, layout, data) }
+  }
+}
+
+/// Either a borrowed NDSlice or an owned NDBox, like Cow<[T]>.
+/// Returned by NDSlice::as_standard_layout().
+pub enum NDCow<'a, T, const N: usize> {
+  Borrowed(NDSlice<'a, T, N>),
+  Owned(NDBox<T, N>),
+}
+
+impl<'a, T, const N: usize> NDCow<'a, T, N> {
+  /// Returns whether the data had to be copied
+  pub fn is_owned(&self) -> bool {
+    matches!(self, Self::Owned(_))
+  }
+
+  /// Creates a shared view of the data
+  pub fn as_slice(&self) -> NDSlice<T, N> {
+    match self {
+      Self::Borrowed(slice) => *slice,
+      Self::Owned(owned) => owned.as_slice(),
+    }
+  }
+
+  /// Extracts the owned data, copying it if it is borrowed
+  pub fn into_owned(self) -> NDBox<T, N> where T: Clone {
+    match self {
+      Self::Borrowed(slice) => slice.to_owned(),
+      Self::Owned(owned) => owned,
+    }
+  }
+}
+
+impl<'a, T, const N: usize> NDSlice<'a, T, N> {
+  /// Returns whether the elements are contiguous in memory in row-major order,
+  /// like the elements of an NDBox.
+  /// (Strides along dimensions of length 1 don't matter, since they are never used.)
+  pub fn is_standard_layout(self) -> bool {
+    let Self { len, stride, .. } = self;
+    if len.size() == 0 {
+      return true
+    }
+
+    let default_stride = len.default_stride();
+    (0..N).all(|dimension| {
+      len.0[dimension] == 1 || stride.0[dimension] == default_stride.0[dimension]
+    })
+  }
+
+  /// Returns the elements as a 1-dimensional Rust slice in row-major order
+  /// if they are contiguous in memory (see is_standard_layout())
+  pub fn as_contiguous(self) -> Option<&'a [T]> {
+    if !self.is_standard_layout() {
+      return None
+    }
+
+    // SAFETY: the slice's elements are the `size` elements starting at `data`
+    Some(unsafe { slice::from_raw_parts(self.data.as_ptr(), self.len.size()) })
+  }
+
+  /// Copies the elements into a new NDBox with a row-major layout.
+  /// Rows that are contiguous in memory are copied with Vec::extend_from_slice()
+  /// (a memcpy for Copy elements); other rows are copied by stepping a pointer along them.
+  pub fn to_owned(self) -> NDBox<T, N> where T: Clone {
+    let len = self.len;
+    let size = len.size();
+    let data: Box<[T]> = match (self.as_contiguous(), N.checked_sub(1)) {
+      (Some(data), _) => data.into(),
+      (None, Some(last_dimension)) => {
+        let run_len = len.0[last_dimension];
+        let run_stride = self.stride.0[last_dimension];
+        let mut runs_len = len;
+        runs_len.0[last_dimension] = 1;
+        let mut data = Vec::with_capacity(size);
+        for index in IndexIterator::new(runs_len).take(runs_len.size()) {
+          // SAFETY: the slice isn't empty (or it would be contiguous),
+          // so `index` and the `run_len` elements along the last dimension are in bounds
+          let start = unsafe { self.location(Index(index)) }.as_ptr();
+          if run_stride == 1 {
+            data.extend_from_slice(unsafe { slice::from_raw_parts(start, run_len) });
+          }
+          else {
+            data.extend((0..run_len).map(|run_index| {
+              unsafe { &*start.add(run_index * run_stride) }.clone()
+            }));
+          }
+        }
+        data.into_boxed_slice()
+      },
+      // A 0-dimensional slice has a single element, so it is always contiguous
+      (None, None) => unreachable!(),
+    };
+    // SAFETY: `data` has one element for each index
+    unsafe { NDBox::from_slice_unchecked(len, data) }
+  }
+
+  /// Like NDSlice::to_owned(), but the new NDBox stores its elements in the given layout
+  pub fn to_owned_in(self, layout: Layout) -> NDBox<T, N> where T: Clone {
+    match layout {
+      Layout::RowMajor => self.to_owned(),
+      Layout::ColumnMajor => {
+        // The column-major order of the elements is the row-major order of the transpose
+        let data = self.transpose().to_owned().to_box();
+        // SAFETY: `data` has one element for each index, in column-major order
+        unsafe { NDBox::from_slice_in_unchecked(self.len, layout, data) }
+      },
+    }
+  }
+
+  /// Returns the slice itself if it has a standard layout (see is_standard_layout()),
+  /// or a row-major copy of it otherwise.
+  /// Either way, NDCow::as_slice() gives a view whose elements are contiguous in memory.
+  pub fn as_standard_layout(self) -> NDCow<'a, T, N> where T: Clone {
+    if self.is_standard_layout() { NDCow::Borrowed(self) }
+    else { NDCow::Owned(self.to_owned()) }
+  }
+}
@@ -5,14 +5,45 @@
 #![feature(slice_ptr_get)]
 #![feature(type_alias_impl_trait)]
 
+mod convolve;
+mod error;
+mod fill;
+mod float;
+mod gather;
+mod layout;
+mod linalg;
+mod mask;
+mod nan;
+mod neighborhood;
 mod ops;
+mod pad;
+mod parallel;
+mod rearrange;
+mod rolling;
+mod search;
+mod sort;
+mod stats;
+mod summation;
+mod tiled;
 mod util;
+pub use convolve::*;
+pub use error::*;
+pub use float::*;
+pub use layout::*;
+pub use linalg::*;
+pub use mask::*;
 pub use ops::*;
+pub use pad::*;
+pub use parallel::*;
+pub use rolling::*;
+pub use stats::*;
+pub use tiled::*;
 
+use std::convert::Infallible;
 use std::iter;
 use std::marker::PhantomData;
-use std::mem::ManuallyDrop;
-use std::ptr::NonNull;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr::{self, NonNull};
 use std::slice;
 use util::*;
 
@@ -77,14 +108,17 @@ impl Default for Bounds {
 // and NDSliceMut<'a, T, N> would be &'a mut ND<T, N>.
 
 /// The N-dimensional analog of Box<[T]>.
-/// The underlying allocation is a Box<[T]> in row-major order.
-/// Both the allocation length and the stride vector can be computed from `len`.
+/// The underlying allocation is a Box<[T]>, in row-major order unless another Layout
+/// is requested. Both the allocation length and the stride vector
+/// can be computed from `len` and `layout`.
 pub struct NDBox<T, const N: usize> {
   /// Pointer to the first element (index [0, ..., 0])
   data: NonNull<T>,
   /// Each dimension's number of indices. The indices along dimension D are 0..len[D].
   /// Each combination of dimension indices is an index into the N-dimensional slice.
   len: Len<N>,
+  /// The order of the elements in the allocation
+  layout: Layout,
 }
 
 /// N-dimensional analog of &[T]
@@ -111,10 +145,87 @@ pub struct NDSliceMut<'a, T, const N: usize> {
   phantom: PhantomData<&'a mut T>,
 }
 
+// NonNull<T> is neither Send nor Sync, so the thread-safety of each type
+// has to be declared to match the type it is the N-dimensional analog of.
+
+/// SAFETY: an NDBox<T, N> owns its elements, like Box<[T]>.
+/// So it can only be sent to another thread if the elements can be:
+/// ```compile_fail,E0277
+/// use std::rc::Rc;
+/// use std::thread;
+/// use nd_slice::NDBox;
+///
+/// let counters = NDBox::new_with([2], |_| Rc::new(0));
+/// thread::spawn(move || drop(counters));
+/// ```
+unsafe impl<T: Send, const N: usize> Send for NDBox<T, N> {}
+
+/// SAFETY: an NDBox<T, N> only gives out shared references to its elements
+/// through a shared reference to it, like Box<[T]>
+unsafe impl<T: Sync, const N: usize> Sync for NDBox<T, N> {}
+
+/// SAFETY: an NDSlice<T, N> gives out shared references to its elements, like &[T].
+/// So it can only be sent to another thread if the elements can be shared between threads:
+/// ```compile_fail,E0277
+/// use std::cell::Cell;
+/// use std::thread;
+/// use nd_slice::NDBox;
+///
+/// let cells = NDBox::new_with([2], |_| Cell::new(0));
+/// let cells = cells.as_slice();
+/// thread::scope(|scope| {
+///   scope.spawn(move || cells.index([0]).set(1));
+/// });
+/// ```
+unsafe impl<T: Sync, const N: usize> Send for NDSlice<'_, T, N> {}
+
+/// SAFETY: like &[T], sharing an NDSlice<T, N> only shares its elements
+unsafe impl<T: Sync, const N: usize> Sync for NDSlice<'_, T, N> {}
+
+/// SAFETY: an NDSliceMut<T, N> has exclusive access to its elements, like &mut [T].
+/// So it can be sent to another thread if the elements can be:
+/// ```compile_fail,E0277
+/// use std::rc::Rc;
+/// use std::thread;
+/// use nd_slice::NDBox;
+///
+/// let mut counters = NDBox::new_with([2], |_| Rc::new(0));
+/// let mut counters = counters.as_mut();
+/// thread::scope(|scope| {
+///   scope.spawn(move || drop(counters.index_mut([0]).clone()));
+/// });
+/// ```
+unsafe impl<T: Send, const N: usize> Send for NDSliceMut<'_, T, N> {}
+
+/// SAFETY: like &mut [T], a shared NDSliceMut<T, N> only gives out shared references.
+/// They borrow the NDSliceMut, so they can't be used after it mutates its elements:
+/// ```compile_fail,E0502
+/// use nd_slice::NDBox;
+///
+/// let mut values = NDBox::new_with([2], |_| 0);
+/// let mut values = values.as_mut();
+/// let view = values.as_slice();
+/// *values.index_mut([0]) = 1;
+/// drop(view);
+/// ```
+unsafe impl<T: Sync, const N: usize> Sync for NDSliceMut<'_, T, N> {}
+
 impl<const N: usize> Len<N> {
-  /// Returns the number of elements in an N-dimensional slice with the given length
+  /// Returns the number of elements in an N-dimensional slice with the given length.
+  /// Panics if the number of elements doesn't fit in a usize.
   fn size(self) -> usize {
-    self.0.iter().product()
+    self.checked_size().unwrap_or_else(|error| panic!("{}", error))
+  }
+
+  /// Like size(), but returns an error if the number of elements doesn't fit in a usize
+  fn checked_size(self) -> Result<usize, NDError> {
+    // An empty dimension makes the slice empty, however long the other dimensions are
+    if self.0.contains(&0) {
+      return Ok(0)
+    }
+
+    self.0.iter().try_fold(1usize, |size, &dimension_len| size.checked_mul(dimension_len))
+      .ok_or_else(|| NDError::SizeOverflow { len: self.0.to_vec() })
   }
 
   /// Returns the effective stride vector for an N-dimensional box's length.
@@ -126,10 +237,64 @@ impl<const N: usize> Len<N> {
     let mut next_stride = 1;
     for (dimension_stride, dimension_len) in iter::zip(&mut stride.0, self.0).rev() {
       *dimension_stride = next_stride;
-      next_stride *= dimension_len;
+      // This can only overflow if another dimension is empty,
+      // in which case there are no elements and the strides are never used
+      next_stride = next_stride.saturating_mul(dimension_len);
     }
     stride
   }
+
+  /// Applies `bounds` to each dimension (see NDSlice::slice()), returning the index
+  /// where the sliced elements start and their length.
+  /// Returns an error if any range is backwards or extends past its dimension.
+  fn slice(self, bounds: [Bounds; N]) -> Result<(Index<N>, Len<N>), NDError> {
+    let mut start = Index([0; N]);
+    let mut len = self;
+    for (dimension, dimension_bounds) in bounds.into_iter().enumerate() {
+      let dimension_len = self.0[dimension];
+      let dimension_start = dimension_bounds.start.unwrap_or(0);
+      let dimension_end = dimension_bounds.end.unwrap_or(dimension_len);
+      let range = dimension_start..dimension_end;
+      if !(dimension_start <= dimension_end && dimension_end <= dimension_len) {
+        return Err(NDError::RangeOutOfBounds { dimension, range, dimension_len })
+      }
+
+      start.0[dimension] = dimension_start;
+      len.0[dimension] = range.step_by(dimension_bounds.step).len();
+    }
+    Ok((start, len))
+  }
+
+  /// Returns the stride vector for an N-dimensional box's length with the given layout
+  fn layout_stride(self, layout: Layout) -> Stride<N> {
+    match layout {
+      Layout::RowMajor => self.default_stride(),
+      Layout::ColumnMajor => {
+        // Column-major order is row-major order with the dimensions reversed
+        let mut reversed_len = self;
+        reversed_len.0.reverse();
+        let mut stride = reversed_len.default_stride();
+        stride.0.reverse();
+        stride
+      },
+    }
+  }
+
+  /// Iterates over all indices in the order their elements are stored in with the given layout.
+  /// Like IndexIterator, the indices repeat infinitely.
+  fn layout_indices(self, layout: Layout) -> impl Iterator<Item = [usize; N]> {
+    let column_major = layout == Layout::ColumnMajor;
+    let mut memory_len = self;
+    if column_major {
+      memory_len.0.reverse();
+    }
+    IndexIterator::new(memory_len).map(move |mut index| {
+      if column_major {
+        index.reverse();
+      }
+      index
+    })
+  }
 }
 
 /// Iterates over all indices from (0, ..., 0) up to `len`, repeating infinitely.
@@ -171,14 +336,21 @@ impl<T, const N: usize> NDBox<T, N> {
   /// Creates an N-dimensional box with the given elements (in row-major order).
   /// SAFETY: `data` must have `len.size()` elements
   unsafe fn from_slice_unchecked(len: Len<N>, data: Box<[T]>) -> Self {
+    Self::from_slice_in_unchecked(len, Layout::RowMajor, data)
+  }
+
+  /// Creates an N-dimensional box with the given elements (in the order given by `layout`).
+  /// SAFETY: `data` must have `len.size()` elements
+  unsafe fn from_slice_in_unchecked(len: Len<N>, layout: Layout, data: Box<[T]>) -> Self {
     debug_assert_eq!(data.len(), len.size());
     let data = NonNull::from(Box::leak(data)).as_non_null_ptr();
-    Self { data, len }
+    Self { data, len, layout }
   }
 
   /// Flattens a N-dimensional slice back into the boxed slice it came from
+  /// (whose elements are in the order given by `self.layout`)
   fn to_box(self) -> Box<[T]> {
-    let Self { data, len } = *ManuallyDrop::new(self);
+    let Self { data, len, .. } = *ManuallyDrop::new(self);
     let data = data.as_ptr();
     let len = len.size();
     // SAFETY: this is the original allocation that was Box::leak()ed
@@ -188,14 +360,16 @@ impl<T, const N: usize> NDBox<T, N> {
   /// Creates a new NDBox of the specified length,
   /// initializing each element by calling the initializer with its index
   pub fn new_with<F: FnMut([usize; N]) -> T>(len: [usize; N], mut init: F) -> Self {
-    let len = Len(len);
-    let mut data = Box::new_uninit_slice(len.size());
-    for (index, value) in IndexIterator::new(len).zip(&mut *data) {
-      // TODO: panic safety: drop the initialized elements if this panics
-      value.write(init(index));
-    }
-    // SAFETY: `data` has length `size(len)` and all elements were written to
-    unsafe { Self::from_slice_unchecked(len, data.assume_init()) }
+    let result = Self::try_new_with(len, |index| Ok::<_, Infallible>(init(index)));
+    result.unwrap_or_else(|never| match never {})
+  }
+
+  /// Like NDBox::new_with(), but the initializer can fail.
+  /// Stops at the first error and returns it, dropping the elements already initialized.
+  pub fn try_new_with<E, F>(len: [usize; N], init: F) -> Result<Self, E>
+    where F: FnMut([usize; N]) -> Result<T, E>
+  {
+    Self::try_new_with_in(len, Layout::RowMajor, init)
   }
 
   /// Creates a new NDBox of the specified length filled with the given value
@@ -208,16 +382,41 @@ impl<T, const N: usize> NDBox<T, N> {
     Self::new_with(len, |_| T::default())
   }
 
+  /// Creates a new NDBox of the specified length without initializing the elements.
+  /// They can be written in any order, e.g. through NDBox::as_mut(),
+  /// and then NDBox::assume_init() converts the box to an NDBox<T, N>.
+  pub fn new_uninit(len: [usize; N]) -> NDBox<MaybeUninit<T>, N> {
+    Self::new_uninit_in(len, Layout::RowMajor)
+  }
+
+  /// Like NDBox::new_default(), but returns an error instead of panicking or aborting
+  /// if the number of elements overflows or the allocation fails.
+  /// This allows handling lengths that come from untrusted input.
+  pub fn try_new_default(len: [usize; N]) -> Result<Self, NDError> where T: Default {
+    let len = Len(len);
+    let size = len.checked_size()?;
+    let mut data = Vec::new();
+    data.try_reserve_exact(size)
+      .map_err(|_| NDError::AllocationFailed { len: len.0.to_vec() })?;
+    // SAFETY: the capacity is at least `size`, and uninitialized elements are valid MaybeUninits
+    unsafe { data.set_len(size) };
+    let mut data = data.into_boxed_slice();
+    let values = iter::repeat_with(|| Ok::<_, Infallible>(T::default()));
+    try_write_all(&mut data, values).unwrap_or_else(|never| match never {});
+    // SAFETY: `data` has length `size(len)` and all elements were written to
+    Ok(unsafe { Self::from_slice_unchecked(len, data.assume_init()) })
+  }
+
   /// Creates a shared view of the data (like Deref for Box)
   pub fn as_slice(&self) -> NDSlice<T, N> {
-    let Self { data, len } = *self;
-    NDSlice { data, len, stride: self.len.default_stride(), phantom: PhantomData }
+    let Self { data, len, layout } = *self;
+    NDSlice { data, len, stride: len.layout_stride(layout), phantom: PhantomData }
   }
 
   /// Creates a mutable view of the data (like DerefMut for Box)
   pub fn as_mut(&mut self) -> NDSliceMut<T, N> {
-    let Self { data, len } = *self;
-    NDSliceMut { data, len, stride: self.len.default_stride(), phantom: PhantomData }
+    let Self { data, len, layout } = *self;
+    NDSliceMut { data, len, stride: len.layout_stride(layout), phantom: PhantomData }
   }
 
   /// Equivalent to NDSlice::get_unchecked()
@@ -234,25 +433,60 @@ impl<T, const N: usize> NDBox<T, N> {
   /// Equivalent to NDSliceMut::get_unchecked_mut()
   /// SAFETY: each dimension index must be less than the corresponding dimension length
   pub unsafe fn get_unchecked_mut(&mut self, index: [usize; N]) -> &mut T {
-    self.as_mut().get_unchecked_mut(index)
+    self.as_mut().into_unchecked_mut(index)
   }
 
   /// Equivalent to NDSliceMut::get_mut()
   pub fn get_mut(&mut self, index: [usize; N]) -> Option<&mut T> {
-    self.as_mut().get_mut(index)
+    self.as_mut().into_mut(index)
   }
 
-  /// Iterates over all elements by value, along with their index
+  /// Iterates over all elements by value, along with their index.
+  /// Elements are yielded in memory order, which is column-major for a column-major box.
   pub fn iter_owned(self) -> impl Iterator<Item = ([usize; N], T)> {
-    IndexIterator::new(self.len).zip(self.to_box().into_vec())
+    let indices = self.len.layout_indices(self.layout);
+    indices.zip(self.to_box().into_vec())
+  }
+
+  /// Flattens the box into its elements in the order given by `layout`,
+  /// moving them if they are stored in a different order
+  fn into_layout_box(self, layout: Layout) -> Box<[T]> {
+    if self.layout == layout {
+      return self.to_box()
+    }
+
+    let source = self.as_slice();
+    let mut data = Box::new_uninit_slice(self.len.size());
+    for (value, index) in data.iter_mut().zip(self.len.layout_indices(layout)) {
+      // SAFETY: `index` is in bounds, and each element is moved out exactly once
+      value.write(unsafe { ptr::read(source.get_unchecked(index)) });
+    }
+    let old_data = Box::into_raw(self.to_box()) as *mut [MaybeUninit<T>];
+    // SAFETY: the elements have been moved, so free the allocation without dropping them.
+    // Every element of `data` has been written to.
+    unsafe {
+      drop(Box::from_raw(old_data));
+      data.assume_init()
+    }
   }
 }
 
 impl<T, const N: usize> Drop for NDBox<T, N> {
   fn drop(&mut self) {
     // Convert the N-dimensional slice back to its Box<[T]> allocation and drop it
-    let Self { data, len } = *self;
-    Self { data, len }.to_box();
+    let Self { data, len, layout } = *self;
+    Self { data, len, layout }.to_box();
+  }
+}
+
+impl<T, const N: usize> NDBox<MaybeUninit<T>, N> {
+  /// Converts a box created by NDBox::new_uninit() to an initialized box,
+  /// like Box::<[MaybeUninit<T>]>::assume_init().
+  /// SAFETY: every element must have been initialized.
+  /// (If the box is dropped instead, the initialized elements are leaked, not dropped.)
+  pub unsafe fn assume_init(self) -> NDBox<T, N> {
+    let Self { len, layout, .. } = self;
+    NDBox::from_slice_in_unchecked(len, layout, self.to_box().assume_init())
   }
 }
 
@@ -288,6 +522,58 @@ impl<'a, T, const N: usize> NDSlice<'a, T, N> {
     (0..self.len.0[0]).map(move |index0| self.extract::<0>(index0))
   }
 
+  /// Iterates over the 1-dimensional slices along dimension `D`
+  /// (one for each combination of indices along the other dimensions).
+  /// Each is returned along with the index of its first element.
+  fn lanes<const D: usize>(self) -> impl Iterator<Item = ([usize; N], NDSlice<'a, T, 1>)>
+    where Is<{D < N}>: True
+  {
+    let mut lanes_len = self.len;
+    lanes_len.0[D] = 1;
+    IndexIterator::new(lanes_len).take(lanes_len.size()).map(move |index| {
+      // SAFETY: `index` is in bounds, except along dimension D where it is 0,
+      // which is at most the dimension length
+      let data = unsafe { self.location(Index(index)) };
+      let len = Len([self.len.0[D]]);
+      let stride = Stride([self.stride.0[D]]);
+      (index, NDSlice { data, len, stride, phantom: PhantomData })
+    })
+  }
+
+  /// Maps each lane along dimension `D` (see lanes()) to the `dimension_len` values
+  /// along dimension `D` of the result, producing a new boxed slice
+  fn map_lanes<const D: usize, U, I, F>(self, dimension_len: usize, mut f: F) -> NDBox<U, N>
+    where
+      Is<{D < N}>: True,
+      I: IntoIterator<Item = U>,
+      F: FnMut(NDSlice<'a, T, 1>) -> I,
+  {
+    let mut len = self.len.0;
+    len[D] = dimension_len;
+    // Fill in the results one lane at a time, then unwrap them
+    let mut results = NDBox::new_with(len, |_| None);
+    let mut results_slice = results.as_mut();
+    for (mut index, lane) in self.lanes::<D>() {
+      for (dimension_index, result) in (0..dimension_len).zip(f(lane)) {
+        index[D] = dimension_index;
+        *results_slice.index_mut(index) = Some(result);
+      }
+    }
+    results.map(Option::unwrap)
+  }
+
+  /// Reduces each lane along dimension `D` (see lanes()) to a single value,
+  /// producing a new boxed slice without dimension `D`
+  fn reduce_axis<const D: usize, U, F>(self, f: F) -> NDBox<U, {N - 1}>
+    where Is<{D < N}>: True, F: FnMut(NDSlice<'a, T, 1>) -> U
+  {
+    // The lanes are visited in row-major order of the remaining dimensions
+    let results: Box<[U]> = self.lanes::<D>().map(|(_, lane)| lane).map(f).collect();
+    let len = Len(remove::<N, D>(self.len.0));
+    // SAFETY: there is one lane for each index in `len`
+    unsafe { NDBox::from_slice_unchecked(len, results) }
+  }
+
   /// Accesses the element at the given index, without any bounds-checking.
   /// SAFETY: each dimension index must be less than the corresponding dimension length
   pub unsafe fn get_unchecked(self, index: [usize; N]) -> &'a T {
@@ -315,24 +601,45 @@ impl<'a, T, const N: usize> NDSlice<'a, T, N> {
     unsafe { self.get_unchecked(index.0) }
   }
 
+  /// Like NDSlice::index(), but returns an error describing the first dimension
+  /// where the index is out of bounds
+  pub fn try_index(self, index: [usize; N]) -> Result<&'a T, NDError> {
+    let dimensions = iter::zip(index, self.len.0).enumerate();
+    for (dimension, (dimension_index, dimension_len)) in dimensions {
+      if dimension_index >= dimension_len {
+        return Err(NDError::OutOfBounds { dimension, index: dimension_index, dimension_len })
+      }
+    }
+    // SAFETY: index is in bounds
+    Ok(unsafe { self.get_unchecked(index) })
+  }
+
   /// Picks out the elements at a given index along dimension `D`.
   /// The dimension is required to be a constant so it can be checked at compile time.
   pub fn extract<const D: usize>(self, dimension_index: usize) -> NDSlice<'a, T, {N - 1}>
     where Is<{D < N}>: True
+  {
+    self.try_extract::<D>(dimension_index).unwrap_or_else(|error| panic!("{}", error))
+  }
+
+  /// Like NDSlice::extract(), but returns an error if the index is out of bounds
+  pub fn try_extract<const D: usize>(self, dimension_index: usize)
+    -> Result<NDSlice<'a, T, {N - 1}>, NDError>
+    where Is<{D < N}>: True
   {
     let Self { len, stride, .. } = self;
     let dimension_len = len.0[D];
-    assert!(
-      dimension_index < dimension_len,
-      "index {} out of bounds for dimension of len {}", dimension_index, dimension_len,
-    );
+    if dimension_index >= dimension_len {
+      return Err(NDError::OutOfBounds { dimension: D, index: dimension_index, dimension_len })
+    }
+
     let mut index = Index([0; N]);
     index.0[D] = dimension_index;
     // SAFETY: index is in bounds
     let data = unsafe { self.location(index) };
     let len = Len(remove::<N, D>(len.0));
     let stride = Stride(remove::<N, D>(stride.0));
-    NDSlice { data, len, stride, phantom: PhantomData }
+    Ok(NDSlice { data, len, stride, phantom: PhantomData })
   }
 
   /// Adds a new dimension at index `D` with the given length.
@@ -348,31 +655,38 @@ impl<'a, T, const N: usize> NDSlice<'a, T, N> {
     NDSlice { data, len, stride, phantom: PhantomData }
   }
 
+  /// Repeats the slice `count` times along dimension `D`, which must have length 1.
+  /// Like add_dimension(), this doesn't copy any elements: the dimension's stride becomes 0.
+  /// (NDSlice::tile() and NDSlice::repeat() can repeat dimensions of any length by copying.)
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn repeat_view<const D: usize>(self, count: usize) -> Self where Is<{D < N}>: True {
+    let Self { data, mut len, mut stride, .. } = self;
+    assert!(
+      len.0[D] == 1,
+      "Cannot repeat dimension {} of {:?} without copying", D, len,
+    );
+    len.0[D] = count;
+    stride.0[D] = 0;
+    Self { data, len, stride, phantom: PhantomData }
+  }
+
   /// Restricts the array to a slice along each dimension.
   /// Also allows applying an additional stride with Bounds::step().
   /// To leave a dimension unsliced, use Bounds::all() as its bounds.
   pub fn slice(self, bounds: [Bounds; N]) -> Self {
-    let Self { len, stride, .. } = self;
-    let dimensions = bounds.zip(len.0).zip(stride.0)
-      .map(|((dimension_bounds, dimension_len), dimension_stride)| {
-        let dimension_start = dimension_bounds.start.unwrap_or(0);
-        let dimension_end = dimension_bounds.end.unwrap_or(dimension_len);
-        let dimension_range = dimension_start..dimension_end;
-        assert!(
-          dimension_start <= dimension_end && dimension_end <= dimension_len,
-          "range {:?} out of bounds for dimension of len {}",
-          dimension_range, dimension_len,
-        );
-        let dimension_len = dimension_range.step_by(dimension_bounds.step).len();
-        let dimension_stride = dimension_stride * dimension_bounds.step;
-        (dimension_start, dimension_len, dimension_stride)
-      });
-    let index = Index(dimensions.map(|(dimension_start, _, _)| dimension_start));
-    // SAFETY: `dimension_start`s have been checked to be in bounds
+    self.try_slice(bounds).unwrap_or_else(|error| panic!("{}", error))
+  }
+
+  /// Like NDSlice::slice(), but returns an error if the bounds are out of range
+  pub fn try_slice(self, bounds: [Bounds; N]) -> Result<Self, NDError> {
+    let Self { mut stride, .. } = self;
+    let (index, len) = self.len.slice(bounds)?;
+    for (dimension_stride, dimension_bounds) in iter::zip(&mut stride.0, bounds) {
+      *dimension_stride *= dimension_bounds.step;
+    }
+    // SAFETY: Len::slice() checked that the start of each range is in bounds
     let data = unsafe { self.location(index) };
-    let len = Len(dimensions.map(|(_, dimension_len, _)| dimension_len));
-    let stride = Stride(dimensions.map(|(_, _, dimension_stride)| dimension_stride));
-    Self { data, len, stride, phantom: PhantomData }
+    Ok(Self { data, len, stride, phantom: PhantomData })
   }
 
   /// Reverses the dimensions, so what was at index [a, ..., z] becomes index [z, ..., a].
@@ -396,6 +710,75 @@ impl<'a, T, const N: usize> NDSlice<'a, T, N> {
   pub fn iter(self) -> impl Iterator<Item = ([usize; N], &'a T)> {
     self.indices().map(move |index| (index, self.index(index)))
   }
+
+  /// Iterates over a grid of sub-slices with length `count`.
+  /// Each sub-slice is returned along with its index in the grid,
+  /// and `bounds` computes the sub-slice's bounds from this index.
+  fn sub_slices<F>(self, count: [usize; N], bounds: F)
+    -> impl Iterator<Item = ([usize; N], Self)>
+    where F: Fn([usize; N]) -> [Bounds; N]
+  {
+    let count = Len(count);
+    IndexIterator::new(count).take(count.size())
+      .map(move |index| (index, self.slice(bounds(index))))
+  }
+
+  /// Iterates over all (overlapping) sub-slices with length `size`,
+  /// along with the index of each sub-slice's first element.
+  /// Like <[T]>::windows(), there are no windows if `size` is larger than the slice.
+  pub fn windows(self, size: [usize; N]) -> impl Iterator<Item = ([usize; N], Self)> {
+    assert!(
+      size.iter().all(|&dimension_size| dimension_size > 0),
+      "Window size {:?} is empty", size,
+    );
+    let count = self.len.0.zip(size)
+      .map(|(dimension_len, dimension_size)| (dimension_len + 1).saturating_sub(dimension_size));
+    self.sub_slices(count, move |index| {
+      index.zip(size).map(|(dimension_index, dimension_size)| {
+        Bounds::all().from(dimension_index).to(dimension_index + dimension_size)
+      })
+    })
+  }
+
+  /// Splits the slice into non-overlapping tiles with length `size`,
+  /// starting from index [0, ..., 0].
+  /// If `size` doesn't evenly divide the slice's length,
+  /// the tiles at the end of a dimension will be shorter along that dimension.
+  /// Each tile is returned along with its index in the grid of tiles.
+  pub fn chunks(self, size: [usize; N]) -> impl Iterator<Item = ([usize; N], Self)> {
+    assert!(
+      size.iter().all(|&dimension_size| dimension_size > 0),
+      "Chunk size {:?} is empty", size,
+    );
+    let len = self.len.0;
+    let count = len.zip(size)
+      .map(|(dimension_len, dimension_size)| (dimension_len + dimension_size - 1) / dimension_size);
+    self.sub_slices(count, move |index| {
+      index.zip(size).zip(len).map(|((dimension_index, dimension_size), dimension_len)| {
+        let dimension_start = dimension_index * dimension_size;
+        let dimension_end = (dimension_start + dimension_size).min(dimension_len);
+        Bounds::all().from(dimension_start).to(dimension_end)
+      })
+    })
+  }
+
+  /// Like NDSlice::chunks(), but only returns tiles with exactly length `size`.
+  /// If `size` doesn't evenly divide the slice's length,
+  /// the elements at the end of a dimension are not part of any tile.
+  pub fn exact_chunks(self, size: [usize; N]) -> impl Iterator<Item = ([usize; N], Self)> {
+    assert!(
+      size.iter().all(|&dimension_size| dimension_size > 0),
+      "Chunk size {:?} is empty", size,
+    );
+    let count = self.len.0.zip(size)
+      .map(|(dimension_len, dimension_size)| dimension_len / dimension_size);
+    self.sub_slices(count, move |index| {
+      index.zip(size).map(|(dimension_index, dimension_size)| {
+        let dimension_start = dimension_index * dimension_size;
+        Bounds::all().from(dimension_start).to(dimension_start + dimension_size)
+      })
+    })
+  }
 }
 
 /// Cloning an NDSlice is just copying the pointer, length, and stride.
@@ -412,42 +795,107 @@ impl<T, const N: usize> Copy for NDSlice<'_, T, N> {}
 /// All methods take it by reference so it can be re-borrowed.
 impl<'a, T, const N: usize> NDSliceMut<'a, T, N> {
   /// Creates a shared view of the slice
-  pub fn as_slice(&self) -> NDSlice<'a, T, N> {
+  pub fn as_slice(&self) -> NDSlice<'_, T, N> {
     let Self { data, len, stride, .. } = *self;
     NDSlice { data, len, stride, phantom: PhantomData }
   }
 
   /// Equivalent to NDSlice::get_unchecked(), but mutably.
   /// SAFETY: each dimension index must be less than the corresponding dimension length
-  pub unsafe fn get_unchecked_mut(&mut self, index: [usize; N]) -> &'a mut T {
+  pub unsafe fn get_unchecked_mut(&mut self, index: [usize; N]) -> &mut T {
     as_mut(self.as_slice().get_unchecked(index))
   }
 
+  /// Like NDSliceMut::get_unchecked_mut(), but consumes the slice
+  /// to return a reference with the slice lifetime 'a
+  /// SAFETY: each dimension index must be less than the corresponding dimension length
+  unsafe fn into_unchecked_mut(self, index: [usize; N]) -> &'a mut T {
+    self.as_slice().location(Index(index)).as_mut()
+  }
+
+  /// Like NDSliceMut::get_mut(), but consumes the slice
+  /// to return a reference with the slice lifetime 'a
+  fn into_mut(self, index: [usize; N]) -> Option<&'a mut T> {
+    if !self.as_slice().check_index(Index(index)) {
+      return None
+    }
+
+    // SAFETY: index is in bounds
+    Some(unsafe { self.into_unchecked_mut(index) })
+  }
+
+  /// Like NDSliceMut::index_mut(), but consumes the slice
+  /// to return a reference with the slice lifetime 'a
+  fn into_index_mut(self, index: [usize; N]) -> &'a mut T {
+    let index = Index(index);
+    assert!(self.as_slice().check_index(index), "{:?} out of bounds for {:?}", index, self.len);
+    // SAFETY: index is in bounds
+    unsafe { self.into_unchecked_mut(index.0) }
+  }
+
   /// Equivalent to NDSlice::get(), but mutably
-  pub fn get_mut(&mut self, index: [usize; N]) -> Option<&'a mut T> {
+  pub fn get_mut(&mut self, index: [usize; N]) -> Option<&mut T> {
     // SAFETY: `self` has mutable access to all its values
     self.as_slice().get(index).map(|value| unsafe { as_mut(value) })
   }
 
-  /// Like ops::IndexMut but returns a reference with the slice lifetime 'a
-  /// rather than the lifetime of the borrow (&mut self)
-  pub fn index_mut(&mut self, index: [usize; N]) -> &'a mut T {
+  /// Equivalent to NDSlice::index(), but mutably (like ops::IndexMut)
+  pub fn index_mut(&mut self, index: [usize; N]) -> &mut T {
     // SAFETY: `self` has mutable access to all its values
     unsafe { as_mut(self.as_slice().index(index)) }
   }
 
+  /// Equivalent to NDSlice::try_index(), but mutably
+  pub fn try_index_mut(&mut self, index: [usize; N]) -> Result<&mut T, NDError> {
+    // SAFETY: `self` has mutable access to all its values
+    self.as_slice().try_index(index).map(|value| unsafe { as_mut(value) })
+  }
+
+  /// Swaps the elements at two indices, like <[T]>::swap()
+  pub fn swap(&mut self, index1: [usize; N], index2: [usize; N]) {
+    let slice = self.as_slice();
+    let index1 = Index(index1);
+    let index2 = Index(index2);
+    for index in [index1, index2] {
+      assert!(slice.check_index(index), "{:?} out of bounds for {:?}", index, self.len);
+    }
+    // SAFETY: both indices are in bounds, and ptr::swap() allows them to be the same element
+    unsafe { ptr::swap(slice.location(index1).as_ptr(), slice.location(index2).as_ptr()) }
+  }
+
+  /// Equivalent to NDSlice::lanes(), but mutably
+  fn lanes_mut<const D: usize>(&mut self)
+    -> impl Iterator<Item = ([usize; N], NDSliceMut<T, 1>)> + '_
+    where Is<{D < N}>: True
+  {
+    self.as_slice().lanes::<D>().map(|(index, lane)| {
+      let NDSlice { data, len, stride, .. } = lane;
+      // The lanes don't overlap, so no mutable references will alias
+      (index, NDSliceMut { data, len, stride, phantom: PhantomData })
+    })
+  }
+
   /// Equivalent to NDSlice::extract(), but mutably
   pub fn extract_mut<const D: usize>(&mut self, dimension_index: usize)
-    -> NDSliceMut<'a, T, {N - 1}>
+    -> NDSliceMut<'_, T, {N - 1}>
     where Is<{D < N}>: True
   {
     let NDSlice { data, len, stride, .. } = self.as_slice().extract::<D>(dimension_index);
     NDSliceMut { data, len, stride, phantom: PhantomData }
   }
 
+  /// Equivalent to NDSlice::try_extract(), but mutably
+  pub fn try_extract_mut<const D: usize>(&mut self, dimension_index: usize)
+    -> Result<NDSliceMut<'_, T, {N - 1}>, NDError>
+    where Is<{D < N}>: True
+  {
+    let NDSlice { data, len, stride, .. } = self.as_slice().try_extract::<D>(dimension_index)?;
+    Ok(NDSliceMut { data, len, stride, phantom: PhantomData })
+  }
+
   /// Like NDSlice::add_dimension(), but mutably.
   /// Can only add a length of 1; otherwise, mutable references could alias.
-  pub fn add_dimension_mut<const D: usize>(&mut self) -> NDSliceMut<'a, T, {N + 1}>
+  pub fn add_dimension_mut<const D: usize>(&mut self) -> NDSliceMut<'_, T, {N + 1}>
     where Is<{D <= N}>: True
   {
     let NDSlice { data, len, stride, .. } = self.as_slice().add_dimension::<D>(1);
@@ -455,11 +903,17 @@ impl<'a, T, const N: usize> NDSliceMut<'a, T, N> {
   }
 
   /// Equivalent to NDSlice::slice(), but mutably
-  pub fn slice_mut(&mut self, bounds: [Bounds; N]) -> NDSliceMut<'a, T, N> {
+  pub fn slice_mut(&mut self, bounds: [Bounds; N]) -> NDSliceMut<'_, T, N> {
     let NDSlice { data, len, stride, .. } = self.as_slice().slice(bounds);
     NDSliceMut { data, len, stride, phantom: PhantomData }
   }
 
+  /// Equivalent to NDSlice::try_slice(), but mutably
+  pub fn try_slice_mut(&mut self, bounds: [Bounds; N]) -> Result<NDSliceMut<'_, T, N>, NDError> {
+    let NDSlice { data, len, stride, .. } = self.as_slice().try_slice(bounds)?;
+    Ok(NDSliceMut { data, len, stride, phantom: PhantomData })
+  }
+
   /// Equivalent to NDSlice::iter(), but mutably
   pub fn iter_mut(&mut self) -> impl Iterator<Item = ([usize; N], &mut T)> + '_ {
     self.as_slice().iter().map(|(index, value)| {
@@ -469,6 +923,51 @@ impl<'a, T, const N: usize> NDSliceMut<'a, T, N> {
       (index, unsafe { as_mut(value) })
     })
   }
+
+  /// Equivalent to NDSlice::chunks(), but mutably
+  pub fn chunks_mut(&mut self, size: [usize; N])
+    -> impl Iterator<Item = ([usize; N], NDSliceMut<T, N>)> + '_
+  {
+    self.as_slice().chunks(size).map(|(index, chunk)| {
+      let NDSlice { data, len, stride, .. } = chunk;
+      // The chunks don't overlap, so no mutable references will alias
+      (index, NDSliceMut { data, len, stride, phantom: PhantomData })
+    })
+  }
+}
+
+impl<'a, T> NDSlice<'a, T, 2> {
+  /// Views a diagonal of a matrix as a 1-dimensional slice.
+  /// `offset` 0 is the main diagonal (indices [i, i]), a positive `offset` selects
+  /// a diagonal above it (indices [i, i + offset]), and a negative `offset` one below it.
+  /// Moving along the diagonal advances by one along both dimensions,
+  /// so the diagonal's stride is the sum of the matrix's strides.
+  pub fn diagonal(self, offset: isize) -> NDSlice<'a, T, 1> {
+    let Self { len, stride, .. } = self;
+    let start = if offset >= 0 { [0, offset as usize] } else { [offset.unsigned_abs(), 0] };
+    let [start0, start1] = start;
+    let [length0, length1] = len.0;
+    let diagonal_len = length0.saturating_sub(start0).min(length1.saturating_sub(start1));
+    let data =
+      // SAFETY: a non-empty diagonal starts at an index that is in bounds
+      if diagonal_len > 0 { unsafe { self.location(Index(start)) } }
+      else { self.data };
+    let [stride0, stride1] = stride.0;
+    NDSlice {
+      data,
+      len: Len([diagonal_len]),
+      stride: Stride([stride0 + stride1]),
+      phantom: PhantomData,
+    }
+  }
+}
+
+impl<'a, T> NDSliceMut<'a, T, 2> {
+  /// Equivalent to NDSlice::diagonal(), but mutably
+  pub fn diagonal_mut(&mut self, offset: isize) -> NDSliceMut<'_, T, 1> {
+    let NDSlice { data, len, stride, .. } = self.as_slice().diagonal(offset);
+    NDSliceMut { data, len, stride, phantom: PhantomData }
+  }
 }
 
 /// A trait indicating that a slice's values can be iterated.
@@ -498,6 +997,19 @@ pub trait NDIntoIterator<const N: usize>: IntoIterator + Sized {
     iter::zip(self, other)
   }
 
+  /// Like zip(), but returns an error instead of panicking if the lengths differ
+  fn try_zip<I: NDIntoIterator<N>>(self, other: I)
+    -> Result<iter::Zip<Self::IntoIter, I::IntoIter>, NDError>
+  {
+    let len = self.len();
+    let other_len = other.len();
+    if len != other_len {
+      return Err(NDError::ShapeMismatch { len1: len.to_vec(), len2: other_len.to_vec() })
+    }
+
+    Ok(iter::zip(self, other))
+  }
+
   /// Zips the corresponding values of two slices with the same length together,
   /// mapping each pair of values according to a function to produce a new boxed slice
   fn zip_map<U, I, F>(self, other: I, mut f: F) -> NDBox<U, N>
@@ -510,6 +1022,49 @@ pub trait NDIntoIterator<const N: usize>: IntoIterator + Sized {
     // SAFETY: `data` has as many elements as `self` and `other`, which have length `len`
     unsafe { NDBox::from_slice_unchecked(len, data) }
   }
+
+  /// Compares the corresponding values of two slices with the same length using ==,
+  /// producing a boxed slice of the results
+  fn eq_elem<I>(self, other: I) -> NDBox<bool, N>
+    where I: NDIntoIterator<N>, Self::Item: PartialEq<I::Item>
+  {
+    self.zip_map(other, |a, b| a == b)
+  }
+
+  /// Like eq_elem(), but compares using !=
+  fn ne_elem<I>(self, other: I) -> NDBox<bool, N>
+    where I: NDIntoIterator<N>, Self::Item: PartialEq<I::Item>
+  {
+    self.zip_map(other, |a, b| a != b)
+  }
+
+  /// Like eq_elem(), but compares using <
+  fn lt_elem<I>(self, other: I) -> NDBox<bool, N>
+    where I: NDIntoIterator<N>, Self::Item: PartialOrd<I::Item>
+  {
+    self.zip_map(other, |a, b| a < b)
+  }
+
+  /// Like eq_elem(), but compares using <=
+  fn le_elem<I>(self, other: I) -> NDBox<bool, N>
+    where I: NDIntoIterator<N>, Self::Item: PartialOrd<I::Item>
+  {
+    self.zip_map(other, |a, b| a <= b)
+  }
+
+  /// Like eq_elem(), but compares using >
+  fn gt_elem<I>(self, other: I) -> NDBox<bool, N>
+    where I: NDIntoIterator<N>, Self::Item: PartialOrd<I::Item>
+  {
+    self.zip_map(other, |a, b| a > b)
+  }
+
+  /// Like eq_elem(), but compares using >=
+  fn ge_elem<I>(self, other: I) -> NDBox<bool, N>
+    where I: NDIntoIterator<N>, Self::Item: PartialOrd<I::Item>
+  {
+    self.zip_map(other, |a, b| a >= b)
+  }
 }
 
 impl<T, const N: usize> IntoIterator for NDBox<T, N> {
@@ -517,7 +1072,7 @@ impl<T, const N: usize> IntoIterator for NDBox<T, N> {
   type IntoIter = impl Iterator<Item = T>;
 
   fn into_iter(self) -> Self::IntoIter {
-    self.to_box().into_vec().into_iter()
+    self.into_layout_box(Layout::RowMajor).into_vec().into_iter()
   }
 }
 
@@ -0,0 +1,247 @@
+use std::iter::{self, Product, Sum};
+use std::ops::Mul;
+use super::{matrix_product, Float, NDBox, NDIntoIterator, NDSlice};
+
+/// The degree of the numerator and denominator of the Padé approximant used by expm()
+const PADE_DEGREE: i32 = 6;
+
+/// Computes the dot product of two vectors with the same length,
+/// i.e. the sum of the products of their corresponding elements
+pub fn dot<'a, 'b, T, U, O>(vector1: NDSlice<'a, T, 1>, vector2: NDSlice<'b, U, 1>) -> O where
+  &'a T: Mul<&'b U>,
+  O: Sum<<&'a T as Mul<&'b U>>::Output>,
+{
+  let len1 = vector1.len;
+  let len2 = vector2.len;
+  assert!(len1 == len2, "Cannot multiply vectors of {:?} and {:?}", len1, len2);
+  iter::zip(vector1, vector2).map(|(value1, value2)| value1 * value2).sum()
+}
+
+/// Multiplies a matrix with length [l0, l_inner] by a vector with length [l_inner],
+/// producing a vector with length [l0].
+/// Index [i] of the result is the dot product of row i of the matrix with the vector.
+pub fn matvec<'a, 'b, T, U, O>(matrix: NDSlice<'a, T, 2>, vector: NDSlice<'b, U, 1>)
+  -> NDBox<O, 1> where
+  &'a T: Mul<&'b U>,
+  O: Sum<<&'a T as Mul<&'b U>>::Output>,
+{
+  let matrix_len = matrix.len;
+  let vector_len = vector.len;
+  let [length, inner_length1] = matrix_len.0;
+  let [inner_length2] = vector_len.0;
+  assert!(
+    inner_length1 == inner_length2,
+    "Cannot multiply matrix of {:?} by vector of {:?}", matrix_len, vector_len,
+  );
+  NDBox::new_with([length], |[index]| dot(matrix.extract::<0>(index), vector))
+}
+
+/// Computes the outer product of two vectors with lengths [l0] and [l1],
+/// producing a matrix with length [l0, l1].
+/// Index [i0, i1] of the result is the product of element i0 of `vector1`
+/// and element i1 of `vector2`.
+pub fn outer<'a, 'b, T, U>(vector1: NDSlice<'a, T, 1>, vector2: NDSlice<'b, U, 1>)
+  -> NDBox<<&'a T as Mul<&'b U>>::Output, 2>
+  where &'a T: Mul<&'b U>
+{
+  let [length0] = vector1.len.0;
+  let [length1] = vector2.len.0;
+  NDBox::new_with([length0, length1], |[index0, index1]| {
+    vector1.index([index0]) * vector2.index([index1])
+  })
+}
+
+/// Computes the Kronecker product of two N-dimensional slices.
+/// The result's length is the product of the slices' lengths along each dimension.
+/// It consists of a block for each element of `slice1`,
+/// which is that element multiplied by all of `slice2`.
+pub fn kron<'a, 'b, T, U, const N: usize>(slice1: NDSlice<'a, T, N>, slice2: NDSlice<'b, U, N>)
+  -> NDBox<<&'a T as Mul<&'b U>>::Output, N>
+  where &'a T: Mul<&'b U>
+{
+  let len1 = slice1.len.0;
+  let len2 = slice2.len.0;
+  let mut len = len1;
+  for (dimension_len, dimension_len2) in iter::zip(&mut len, len2) {
+    *dimension_len *= dimension_len2;
+  }
+  NDBox::new_with(len, |index| {
+    let mut index1 = index;
+    let mut index2 = index;
+    for ((dimension_index1, dimension_index2), dimension_len2) in
+      iter::zip(iter::zip(&mut index1, &mut index2), len2)
+    {
+      *dimension_index1 /= dimension_len2;
+      *dimension_index2 %= dimension_len2;
+    }
+    slice1.index(index1) * slice2.index(index2)
+  })
+}
+
+impl<T> NDBox<T, 2> {
+  /// Creates the identity matrix with the given length.
+  /// The 0 and 1 values are the empty sum and the empty product.
+  pub fn eye(len: usize) -> Self where T: Sum + Product {
+    Self::new_with([len, len], |[index0, index1]| {
+      if index0 == index1 { iter::empty().product() } else { iter::empty().sum() }
+    })
+  }
+
+  /// Creates a square matrix with the given values along its main diagonal
+  /// and 0 (the empty sum) everywhere else
+  pub fn from_diagonal(diagonal: NDSlice<T, 1>) -> Self where T: Clone + Sum {
+    let [len] = diagonal.len.0;
+    Self::new_with([len, len], |[index0, index1]| {
+      if index0 == index1 { diagonal.index([index0]).clone() } else { iter::empty().sum() }
+    })
+  }
+}
+
+/// Copies the lower triangle of a matrix, i.e. the elements on or below
+/// the diagonal with the given offset (see NDSlice::diagonal()).
+/// The elements above that diagonal are set to 0 (the empty sum).
+pub fn tril<T: Clone + Sum>(matrix: NDSlice<T, 2>, offset: isize) -> NDBox<T, 2> {
+  NDBox::new_with(matrix.len.0, |[index0, index1]| {
+    if index1 as isize - index0 as isize <= offset { matrix.index([index0, index1]).clone() }
+    else { iter::empty().sum() }
+  })
+}
+
+/// Copies the upper triangle of a matrix, i.e. the elements on or above
+/// the diagonal with the given offset (see NDSlice::diagonal()).
+/// The elements below that diagonal are set to 0 (the empty sum).
+pub fn triu<T: Clone + Sum>(matrix: NDSlice<T, 2>, offset: isize) -> NDBox<T, 2> {
+  NDBox::new_with(matrix.len.0, |[index0, index1]| {
+    if index1 as isize - index0 as isize >= offset { matrix.index([index0, index1]).clone() }
+    else { iter::empty().sum() }
+  })
+}
+
+/// Returns the length of a square matrix, panicking if it isn't square
+fn square_len<T>(matrix: NDSlice<T, 2>, operation: &str) -> usize {
+  let len = matrix.len;
+  let [length0, length1] = len.0;
+  assert!(length0 == length1, "Cannot {} non-square matrix of {:?}", operation, len);
+  length0
+}
+
+/// Computes `matrix` raised to the power `exponent` by repeated squaring,
+/// which requires O(log(exponent)) matrix products.
+/// The matrix must be square. The 0th power is the identity matrix.
+pub fn matrix_power<T>(matrix: NDSlice<T, 2>, mut exponent: u32) -> NDBox<T, 2> where
+  T: Clone + Sum + Product,
+  for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+  let len = square_len(matrix, "raise to a power");
+  // `power` is matrix^(2^i) for the current bit i of `exponent`
+  let mut power = matrix.map(T::clone);
+  let mut result: Option<NDBox<T, 2>> = None;
+  while exponent > 0 {
+    if exponent & 1 != 0 {
+      result = Some(match result {
+        Some(result) => matrix_product(result.as_slice(), power.as_slice()),
+        None => power.clone(),
+      });
+    }
+    exponent >>= 1;
+    if exponent > 0 {
+      power = matrix_product(power.as_slice(), power.as_slice());
+    }
+  }
+  result.unwrap_or_else(|| NDBox::eye(len))
+}
+
+/// Swaps two rows of a matrix
+fn swap_rows<T: Copy>(matrix: &mut NDBox<T, 2>, row1: usize, row2: usize) {
+  let [_, columns] = matrix.len.0;
+  for column in 0..columns {
+    let value1 = matrix[[row1, column]];
+    matrix[[row1, column]] = matrix[[row2, column]];
+    matrix[[row2, column]] = value1;
+  }
+}
+
+/// Solves the linear system `matrix` * X = `rhs` for X
+/// using Gauss-Jordan elimination with partial pivoting.
+/// `matrix` is assumed to be square and invertible.
+fn solve<T: Float>(mut matrix: NDBox<T, 2>, mut rhs: NDBox<T, 2>) -> NDBox<T, 2> {
+  let [len, _] = matrix.len.0;
+  let [_, rhs_columns] = rhs.len.0;
+  for column in 0..len {
+    // Swap the row with the largest value in this column into the pivot position
+    let pivot_row = (column..len)
+      .reduce(|row1, row2| {
+        if matrix[[row2, column]].abs() > matrix[[row1, column]].abs() { row2 } else { row1 }
+      })
+      .unwrap();
+    swap_rows(&mut matrix, column, pivot_row);
+    swap_rows(&mut rhs, column, pivot_row);
+
+    // Scale the pivot row so the pivot is 1, then eliminate the column from the other rows
+    let pivot = matrix[[column, column]];
+    for index1 in 0..len {
+      matrix[[column, index1]] = matrix[[column, index1]] / pivot;
+    }
+    for index1 in 0..rhs_columns {
+      rhs[[column, index1]] = rhs[[column, index1]] / pivot;
+    }
+    for row in (0..len).filter(|&row| row != column) {
+      let factor = matrix[[row, column]];
+      for index1 in 0..len {
+        matrix[[row, index1]] = matrix[[row, index1]] - factor * matrix[[column, index1]];
+      }
+      for index1 in 0..rhs_columns {
+        rhs[[row, index1]] = rhs[[row, index1]] - factor * rhs[[column, index1]];
+      }
+    }
+  }
+  rhs
+}
+
+/// Computes the matrix exponential e^`matrix` = I + A + A^2 / 2! + A^3 / 3! + ...
+/// using scaling and squaring with a Padé approximant
+/// (Golub and Van Loan, "Matrix Computations", algorithm 11.3.1).
+/// The matrix must be square.
+pub fn expm<T: Float>(matrix: NDSlice<T, 2>) -> NDBox<T, 2>
+  where for<'a> &'a T: Mul<&'a T, Output = T>
+{
+  let len = square_len(matrix, "exponentiate");
+
+  // e^A = (e^(A / 2^s))^(2^s), so scale A down until its infinity norm is at most 1/2,
+  // where the Padé approximant is accurate
+  let norm = matrix.rows()
+    .map(|row| row.into_iter().map(|value| value.abs()).sum())
+    .fold(T::ZERO, |max_norm, row_norm| if row_norm > max_norm { row_norm } else { max_norm });
+  let half = T::from_f64(0.5);
+  let mut scale = T::ONE;
+  let mut squarings = 0;
+  while norm * scale > half {
+    scale = scale * half;
+    squarings += 1;
+  }
+  let scaled = matrix.map(|&value| value * scale);
+  let scaled = scaled.as_slice();
+
+  // e^A ~= D^-1 * N, where N = sum of c_k * A^k and D = sum of c_k * (-A)^k
+  let mut numerator = NDBox::eye(len);
+  let mut denominator = NDBox::eye(len);
+  let mut power = NDBox::eye(len);
+  let mut coefficient = 1.0;
+  for k in 1..=PADE_DEGREE {
+    coefficient *=
+      (PADE_DEGREE - k + 1) as f64 / (k * (2 * PADE_DEGREE - k + 1)) as f64;
+    power = matrix_product(power.as_slice(), scaled);
+    let coefficient = T::from_f64(coefficient);
+    let denominator_coefficient = if k % 2 == 0 { coefficient } else { -coefficient };
+    for (index, &value) in power.as_slice().iter() {
+      numerator[index] = numerator[index] + coefficient * value;
+      denominator[index] = denominator[index] + denominator_coefficient * value;
+    }
+  }
+  let mut result = solve(denominator, numerator);
+
+  for _ in 0..squarings {
+    result = matrix_product(result.as_slice(), result.as_slice());
+  }
+  result
+}
@@ -34,10 +34,11 @@ fn main() {
     (temperatures_fahrenheit.as_slice() - const_32) / const_1_8;
   let temperatures_celsius = temperatures_celsius.as_slice();
   dbg!(temperatures_celsius);
-  let average_temperatures = NDBox::new_with([cities], |[city]| {
-    let city_temperatures = temperatures_celsius.extract::<1>(city);
-    city_temperatures.into_iter().sum::<f32>() / days as f32
-  });
+  let average_temperatures = temperatures_celsius.mean_axis::<0>();
   let average_temperatures = average_temperatures.as_slice();
   dbg!(average_temperatures);
+  let temperature_correlations = correlation(temperatures_celsius);
+  dbg!(temperature_correlations.as_slice());
+  let weekly_average_temperatures = temperatures_celsius.rolling::<0>(7).mean();
+  dbg!(weekly_average_temperatures);
 }
@@ -0,0 +1,71 @@
+use super::{Len, NDBox, NDIntoIterator, NDSlice, NDSliceMut};
+
+/// Masks are boolean slices, e.g. produced by NDIntoIterator::lt_elem().
+/// They can be combined element-wise with the ! (Not), & (BitAnd), and | (BitOr) operators.
+impl<const N: usize> NDSlice<'_, bool, N> {
+  /// Returns whether any value is true (false if the slice is empty)
+  pub fn any(self) -> bool {
+    self.into_iter().any(|&value| value)
+  }
+
+  /// Returns whether every value is true (true if the slice is empty)
+  pub fn all(self) -> bool {
+    self.into_iter().all(|&value| value)
+  }
+}
+
+impl<const N: usize> NDBox<bool, N> {
+  /// Equivalent to NDSlice::any()
+  pub fn any(&self) -> bool {
+    self.as_slice().any()
+  }
+
+  /// Equivalent to NDSlice::all()
+  pub fn all(&self) -> bool {
+    self.as_slice().all()
+  }
+}
+
+/// Chooses values from two slices according to a mask, producing a new boxed slice.
+/// Each index of the result is the value from `if_true` where `mask` is true,
+/// and the value from `if_false` otherwise. All three slices must have the same length.
+pub fn where_<T: Clone, const N: usize>(
+  mask: NDSlice<bool, N>,
+  if_true: NDSlice<T, N>,
+  if_false: NDSlice<T, N>,
+) -> NDBox<T, N> {
+  let len = mask.len;
+  assert!(
+    len == if_true.len && len == if_false.len,
+    "Cannot operate on NDSlices with {:?}, {:?}, and {:?}", len, if_true.len, if_false.len,
+  );
+  NDBox::new_with(len.0, |index| {
+    let chosen = if *mask.index(index) { if_true } else { if_false };
+    chosen.index(index).clone()
+  })
+}
+
+impl<'a, T: Clone, const N: usize> NDSlice<'a, T, N> {
+  /// Copies the values where `mask` (which must have the same length) is true,
+  /// in row-major order
+  pub fn masked_select(self, mask: NDSlice<bool, N>) -> NDBox<T, 1> {
+    let values: Box<[T]> = self.zip(mask)
+      .filter(|(_, &selected)| selected)
+      .map(|(value, _)| value.clone())
+      .collect();
+    let len = Len([values.len()]);
+    // SAFETY: `values` has `len` elements
+    unsafe { NDBox::from_slice_unchecked(len, values) }
+  }
+}
+
+impl<'a, T: Clone, const N: usize> NDSliceMut<'a, T, N> {
+  /// Sets the values where `mask` (which must have the same length) is true to `value`
+  pub fn masked_fill(&mut self, mask: NDSlice<bool, N>, value: T) {
+    for (element, &selected) in self.zip(mask) {
+      if selected {
+        *element = value.clone();
+      }
+    }
+  }
+}
@@ -0,0 +1,109 @@
+use super::{pairwise_sum, Float, Is, NDBox, NDIntoIterator, NDSlice, True};
+
+/// Iterates over the non-NaN values of a slice, along with their indices
+fn non_nan<T: Float, const N: usize>(slice: NDSlice<T, N>)
+  -> impl Iterator<Item = ([usize; N], T)> + '_
+{
+  slice.iter().map(|(index, &value)| (index, value)).filter(|(_, value)| !value.is_nan())
+}
+
+fn nansum<T: Float, const N: usize>(slice: NDSlice<T, N>) -> T {
+  pairwise_sum(non_nan(slice).map(|(_, value)| value))
+}
+
+fn nanmean<T: Float, const N: usize>(slice: NDSlice<T, N>) -> T {
+  let count = non_nan(slice).count();
+  // If every value is NaN, this computes 0 / 0, which is NaN
+  nansum(slice) / T::from_f64(count as f64)
+}
+
+/// Finds the first non-NaN value (and its index) that `replaces(new, old)` prefers
+/// over every earlier non-NaN value
+fn nan_extreme<T: Float, F, const N: usize>(slice: NDSlice<T, N>, replaces: F)
+  -> Option<([usize; N], T)>
+  where F: Fn(T, T) -> bool
+{
+  non_nan(slice).reduce(|extreme, next| if replaces(next.1, extreme.1) { next } else { extreme })
+}
+
+fn nanmin<T: Float, const N: usize>(slice: NDSlice<T, N>) -> T {
+  nan_extreme(slice, |new, old| new < old).map_or(T::NAN, |(_, value)| value)
+}
+
+fn nanmax<T: Float, const N: usize>(slice: NDSlice<T, N>) -> T {
+  nan_extreme(slice, |new, old| new > old).map_or(T::NAN, |(_, value)| value)
+}
+
+fn nanargmax<T: Float, const N: usize>(slice: NDSlice<T, N>) -> Option<[usize; N]> {
+  nan_extreme(slice, |new, old| new > old).map(|(index, _)| index)
+}
+
+/// Reductions for floating-point data that treat NaN as a missing value and skip it.
+/// The per-axis versions reduce along dimension `D`, producing a new boxed slice
+/// without dimension `D`. The dimension is required to be a constant
+/// so it can be checked at compile time.
+impl<'a, T: Float, const N: usize> NDSlice<'a, T, N> {
+  /// Computes the sum of the non-NaN values (0 if there are none)
+  pub fn nansum(self) -> T {
+    nansum(self)
+  }
+
+  /// Computes the mean of the non-NaN values (NaN if there are none)
+  pub fn nanmean(self) -> T {
+    nanmean(self)
+  }
+
+  /// Computes the minimum of the non-NaN values (NaN if there are none)
+  pub fn nanmin(self) -> T {
+    nanmin(self)
+  }
+
+  /// Computes the maximum of the non-NaN values (NaN if there are none)
+  pub fn nanmax(self) -> T {
+    nanmax(self)
+  }
+
+  /// Finds the index of the maximum non-NaN value, or None if there are none.
+  /// If the maximum occurs multiple times, the first index (in row-major order) is returned.
+  pub fn nanargmax(self) -> Option<[usize; N]> {
+    nanargmax(self)
+  }
+
+  /// Computes NDSlice::nansum() along dimension `D`
+  pub fn nansum_axis<const D: usize>(self) -> NDBox<T, {N - 1}> where Is<{D < N}>: True {
+    self.reduce_axis::<D, _, _>(nansum)
+  }
+
+  /// Computes NDSlice::nanmean() along dimension `D`
+  pub fn nanmean_axis<const D: usize>(self) -> NDBox<T, {N - 1}> where Is<{D < N}>: True {
+    self.reduce_axis::<D, _, _>(nanmean)
+  }
+
+  /// Computes NDSlice::nanmin() along dimension `D`
+  pub fn nanmin_axis<const D: usize>(self) -> NDBox<T, {N - 1}> where Is<{D < N}>: True {
+    self.reduce_axis::<D, _, _>(nanmin)
+  }
+
+  /// Computes NDSlice::nanmax() along dimension `D`
+  pub fn nanmax_axis<const D: usize>(self) -> NDBox<T, {N - 1}> where Is<{D < N}>: True {
+    self.reduce_axis::<D, _, _>(nanmax)
+  }
+
+  /// Computes NDSlice::nanargmax() along dimension `D`,
+  /// giving the index of the maximum along dimension `D`
+  pub fn nanargmax_axis<const D: usize>(self) -> NDBox<Option<usize>, {N - 1}>
+    where Is<{D < N}>: True
+  {
+    self.reduce_axis::<D, _, _>(|lane| nanargmax(lane).map(|[index]| index))
+  }
+
+  /// Computes a mask of which values are NaN
+  pub fn is_nan(self) -> NDBox<bool, N> {
+    self.map(|value| value.is_nan())
+  }
+
+  /// Computes a mask of which values are finite (neither infinite nor NaN)
+  pub fn is_finite(self) -> NDBox<bool, N> {
+    self.map(|value| value.is_finite())
+  }
+}
@@ -0,0 +1,59 @@
+use super::{Bounds, NDBox, NDSlice, NDSliceMut, PadMode};
+
+/// Copies a slice, extending it by `radius` along each dimension in both directions.
+/// The new elements are filled according to `mode`.
+fn pad_radius<T: Clone, const N: usize>(
+  slice: NDSlice<T, N>,
+  radius: [usize; N],
+  mode: PadMode<T>,
+) -> NDBox<T, N> {
+  // If the slice is empty, it has no neighborhoods, so there is nothing to pad
+  if slice.len.size() == 0 {
+    return NDBox::new_with([0; N], |_| unreachable!())
+  }
+
+  slice.pad(radius.map(|dimension_radius| (dimension_radius, dimension_radius)), mode)
+}
+
+/// Returns the bounds of the neighborhood of `index` in a slice padded by `radius`
+fn neighborhood_bounds<const N: usize>(index: [usize; N], radius: [usize; N]) -> [Bounds; N] {
+  index.zip(radius).map(|(dimension_index, dimension_radius)| {
+    Bounds::all().from(dimension_index).to(dimension_index + 2 * dimension_radius + 1)
+  })
+}
+
+impl<'a, T: Clone, const N: usize> NDSlice<'a, T, N> {
+  /// Maps each element of the slice according to its neighborhood,
+  /// producing a new boxed slice with the same length.
+  /// The neighborhood of index i is the slice of indices i - radius through i + radius
+  /// (so its length is 2 * radius + 1), centered on the element.
+  /// `mode` determines the values of neighbors outside the slice, as in NDSlice::pad().
+  pub fn map_neighborhood<U, F>(self, radius: [usize; N], mode: PadMode<T>, mut f: F)
+    -> NDBox<U, N>
+    where F: FnMut(NDSlice<T, N>) -> U
+  {
+    let padded = pad_radius(self, radius, mode);
+    let padded = padded.as_slice();
+    NDBox::new_with(self.len.0, |index| f(padded.slice(neighborhood_bounds(index, radius))))
+  }
+}
+
+impl<'a, T: Clone, const N: usize> NDSliceMut<'a, T, N> {
+  /// Like NDSlice::map_neighborhood(), but replaces each element with the result.
+  /// The update is double-buffered: all neighborhoods see the values from before the update,
+  /// as in a step of a cellular automaton or a PDE solver.
+  pub fn map_neighborhood_in_place<F>(
+    &mut self,
+    radius: [usize; N],
+    mode: PadMode<T>,
+    mut f: F,
+  ) where F: FnMut(NDSlice<T, N>) -> T
+  {
+    // The padded copy holds the old values, so the slice can be overwritten as we go
+    let padded = pad_radius(self.as_slice(), radius, mode);
+    let padded = padded.as_slice();
+    for (index, value) in self.iter_mut() {
+      *value = f(padded.slice(neighborhood_bounds(index, radius)));
+    }
+  }
+}
@@ -1,12 +1,13 @@
 use std::fmt::{self, Debug, Formatter};
 use std::iter::Sum;
 use std::ops::*;
-use super::{NDBox, NDIntoIterator, NDSlice, NDSliceMut};
+use super::{compensated_sum, Float, NDBox, NDError, NDIntoIterator, NDSlice, NDSliceMut};
 
 /// Clone each element in an NDBox, like Clone for Box<[T]>
+/// The clone has the same layout as the original
 impl<T: Clone, const N: usize> Clone for NDBox<T, N> {
   fn clone(&self) -> Self {
-    self.as_slice().map(T::clone)
+    self.as_slice().to_owned_in(self.layout)
   }
 }
 
@@ -39,8 +40,8 @@ impl<T: Debug, const N: usize> Debug for NDBox<T, N>
   }
 }
 
-impl<'a, T: Debug, const N: usize> Debug for NDSliceMut<'a, T, N>
-  where NDSlice<'a, T, N>: Debug
+impl<T: Debug, const N: usize> Debug for NDSliceMut<'_, T, N>
+  where for<'a> NDSlice<'a, T, N>: Debug
 {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     self.as_slice().fmt(f)
@@ -161,7 +162,7 @@ impl<T, const N: usize> Index<[usize; N]> for NDBox<T, N> {
 
 impl<T, const N: usize> IndexMut<[usize; N]> for NDBox<T, N> {
   fn index_mut(&mut self, index: [usize; N]) -> &mut T {
-    self.as_mut().index_mut(index)
+    self.as_mut().into_index_mut(index)
   }
 }
 
@@ -311,3 +312,43 @@ pub fn matrix_product<'a, 'b, T, U, O>(
     }).sum()
   })
 }
+
+/// Like matrix_product(), but returns an error if the matrices' inner lengths differ
+pub fn matrix_product_checked<'a, 'b, T, U, O>(
+  matrix1: NDSlice<'a, T, 2>,
+  matrix2: NDSlice<'b, U, 2>,
+) -> Result<NDBox<O, 2>, NDError> where
+  &'a T: Mul<&'b U>,
+  O: Sum<<&'a T as Mul<&'b U>>::Output>,
+{
+  let len1 = matrix1.len.0;
+  let len2 = matrix2.len.0;
+  if len1[1] != len2[0] {
+    return Err(NDError::ShapeMismatch { len1: len1.to_vec(), len2: len2.to_vec() })
+  }
+
+  Ok(matrix_product(matrix1, matrix2))
+}
+
+// The naive sums in matrix_product() lose precision when the inner length is large.
+// For floating-point matrices, the products can be accumulated with compensated summation,
+// making the rounding error (to first order) independent of the inner length.
+
+pub fn matrix_product_compensated<T: Float>(
+  matrix1: NDSlice<T, 2>,
+  matrix2: NDSlice<T, 2>,
+) -> NDBox<T, 2> {
+  let len1 = matrix1.len;
+  let len2 = matrix2.len;
+  let [length0, inner_length1] = len1.0;
+  let [inner_length2, length1] = len2.0;
+  assert!(
+    inner_length1 == inner_length2,
+    "Cannot multiply matrices of {:?} and {:?}", len1, len2,
+  );
+  NDBox::new_with([length0, length1], |[index0, index1]| {
+    compensated_sum((0..inner_length1).map(|inner_index| {
+      *matrix1.index([index0, inner_index]) * *matrix2.index([inner_index, index1])
+    }))
+  })
+}
@@ -0,0 +1,84 @@
+use super::{NDBox, NDSlice};
+
+/// How to fill in the elements added by NDSlice::pad().
+/// For example, padding [a, b, c, d] by 3 elements on each side gives
+/// (the new elements are outside the | bars):
+/// - Constant(x): x x x | a b c d | x x x
+/// - Edge: a a a | a b c d | d d d
+/// - Reflect: d c b | a b c d | c b a
+/// - Symmetric: c b a | a b c d | d c b
+/// - Wrap: b c d | a b c d | a b c
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PadMode<T> {
+  /// Every new element has the given value
+  Constant(T),
+  /// New elements take the value at the nearest edge
+  Edge,
+  /// The slice is mirrored about its first and last elements (which are not repeated)
+  Reflect,
+  /// The slice is mirrored about its ends (so the first and last elements are repeated)
+  Symmetric,
+  /// The slice repeats periodically
+  Wrap,
+}
+
+impl<T> PadMode<T> {
+  /// Maps a (possibly out-of-bounds) index along a dimension of length `len`
+  /// to the index whose value it takes, or to the value itself for Constant.
+  /// `len` must not be 0.
+  fn source_index(&self, index: isize, len: usize) -> Result<usize, &T> {
+    let len = len as isize;
+    let source_index =
+      if (0..len).contains(&index) { index }
+      else {
+        match self {
+          Self::Constant(value) => return Err(value),
+          Self::Edge => index.clamp(0, len - 1),
+          Self::Reflect => {
+            // Reflecting about both ends makes the indices periodic with period 2 * (len - 1)
+            let period = (2 * (len - 1)).max(1);
+            let index = index.rem_euclid(period);
+            if index < len { index } else { period - index }
+          },
+          Self::Symmetric => {
+            // Mirroring both ends makes the indices periodic with period 2 * len
+            let period = 2 * len;
+            let index = index.rem_euclid(period);
+            if index < len { index } else { period - 1 - index }
+          },
+          Self::Wrap => index.rem_euclid(len),
+        }
+      };
+    Ok(source_index as usize)
+  }
+}
+
+impl<'a, T: Clone, const N: usize> NDSlice<'a, T, N> {
+  /// Copies the slice into a larger boxed slice, adding `widths[D].0` elements
+  /// before the start and `widths[D].1` elements after the end of each dimension D.
+  /// The new elements are filled in according to `mode`.
+  pub fn pad(self, widths: [(usize, usize); N], mode: PadMode<T>) -> NDBox<T, N> {
+    let len = self.len;
+    let padded_len = len.0.zip(widths)
+      .map(|(dimension_len, (width_before, width_after))| {
+        width_before + dimension_len + width_after
+      });
+    // Only Constant can fill in elements without copying them from the slice
+    assert!(
+      matches!(mode, PadMode::Constant(_)) || len.size() > 0 || padded_len.contains(&0),
+      "Cannot pad empty slice of {:?} except with a constant", len,
+    );
+    NDBox::new_with(padded_len, |mut index| {
+      for ((dimension_index, (width_before, _)), dimension_len) in
+        index.iter_mut().zip(widths).zip(len.0)
+      {
+        let unpadded_index = *dimension_index as isize - width_before as isize;
+        match mode.source_index(unpadded_index, dimension_len) {
+          Ok(source_index) => *dimension_index = source_index,
+          Err(value) => return value.clone(),
+        }
+      }
+      self.index(index).clone()
+    })
+  }
+}
@@ -0,0 +1,195 @@
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::num::NonZeroUsize;
+use std::ops::{Add, Mul, Range};
+use std::panic;
+use std::thread;
+use super::{
+  matrix_product, remove, Bounds, Float, Is, Len, NDBox, NDIntoIterator, NDSlice, NDSliceMut, True,
+};
+
+/// Returns the number of threads the system can run in parallel (at least 1),
+/// a reasonable default for the `threads` argument of the parallel operations
+pub fn available_threads() -> usize {
+  thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Checks that a parallel operation was given at least 1 thread
+fn check_threads(threads: usize) {
+  assert!(threads > 0, "Cannot run on 0 threads");
+}
+
+/// Splits `0..len` into at most `threads` contiguous ranges of nearly equal length
+fn split_ranges(len: usize, threads: usize) -> impl Iterator<Item = Range<usize>> {
+  check_threads(threads);
+  let blocks = threads.min(len).max(1);
+  (0..blocks).map(move |block| block * len / blocks..(block + 1) * len / blocks)
+}
+
+/// Runs `f` on each of the given blocks, each on its own scoped thread,
+/// and concatenates the results in order. A panic in any thread is propagated.
+fn run_blocks<B, U, F>(blocks: Vec<B>, f: F) -> Box<[U]> where
+  B: Send,
+  U: Send,
+  F: Fn(B) -> Box<[U]> + Sync,
+{
+  if blocks.len() <= 1 {
+    // No need to spawn a thread for a single block
+    return blocks.into_iter().map(f).next().unwrap_or_default()
+  }
+
+  let f = &f;
+  let results: Vec<Box<[U]>> = thread::scope(|scope| {
+    let handles: Vec<_> = blocks.into_iter()
+      .map(|block| scope.spawn(move || f(block)))
+      .collect();
+    handles.into_iter()
+      .map(|handle| handle.join().unwrap_or_else(|payload| panic::resume_unwind(payload)))
+      .collect()
+  });
+  results.into_iter().flat_map(Vec::from).collect()
+}
+
+/// Returns the bounds that select `range` along `dimension` (and everything else)
+fn block_bounds<const N: usize>(dimension: usize, range: Range<usize>) -> [Bounds; N] {
+  let mut bounds = [Bounds::all(); N];
+  bounds[dimension] = Bounds::all().from(range.start).to(range.end);
+  bounds
+}
+
+impl<'a, T, const N: usize> NDSlice<'a, T, N> {
+  /// Splits the slice into at most `threads` blocks along `dimension`.
+  /// If `dimension` doesn't exist (e.g. the slice is 0-dimensional), there is one block.
+  fn blocks(self, dimension: usize, threads: usize) -> Vec<Self> {
+    // Check `threads` even when there is nothing to split, so every N has the same precondition
+    check_threads(threads);
+    if dimension >= N {
+      return vec![self]
+    }
+
+    split_ranges(self.len.0[dimension], threads)
+      .map(|range| self.slice(block_bounds(dimension, range)))
+      .collect()
+  }
+
+  /// Like NDIntoIterator::map(), but splits the slice into blocks along the first dimension
+  /// and maps each block on a separate thread, using at most `threads` threads
+  pub fn par_map<U, F>(self, threads: usize, f: F) -> NDBox<U, N> where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync,
+  {
+    let results = run_blocks(self.blocks(0, threads), |block| {
+      block.map(&f).to_box()
+    });
+    // SAFETY: the blocks' results are in row-major order, so they form the whole result
+    unsafe { NDBox::from_slice_unchecked(self.len, results) }
+  }
+
+  /// Like NDIntoIterator::zip_map(), but maps blocks on separate threads (see par_map())
+  pub fn par_zip_map<U, O, F>(self, other: NDSlice<U, N>, threads: usize, f: F) -> NDBox<O, N>
+    where
+      T: Sync,
+      U: Sync,
+      O: Send,
+      F: Fn(&T, &U) -> O + Sync,
+  {
+    let len = self.len;
+    assert!(
+      len == other.len,
+      "Cannot operate on NDSlices with {:?} and {:?}", len, other.len,
+    );
+    let blocks = self.blocks(0, threads).into_iter().zip(other.blocks(0, threads)).collect();
+    let results = run_blocks(blocks, |(block, other_block): (NDSlice<T, N>, NDSlice<U, N>)| {
+      block.zip_map(other_block, &f).to_box()
+    });
+    // SAFETY: the blocks' results are in row-major order, so they form the whole result
+    unsafe { NDBox::from_slice_unchecked(len, results) }
+  }
+
+  /// Reduces each lane along dimension `D` (see reduce_axis()) in parallel.
+  /// The slice is split along the first dimension other than `D`,
+  /// so each thread reduces whole lanes.
+  fn par_reduce_axis<const D: usize, U, F>(self, threads: usize, f: F) -> NDBox<U, {N - 1}>
+    where
+      Is<{D < N}>: True,
+      T: Sync,
+      U: Send,
+      F: Fn(NDSlice<'a, T, 1>) -> U + Sync,
+  {
+    let split_dimension = if D == 0 { 1 } else { 0 };
+    let results = run_blocks(self.blocks(split_dimension, threads), |block| {
+      block.reduce_axis::<D, _, _>(&f).to_box()
+    });
+    let len = Len(remove::<N, D>(self.len.0));
+    // SAFETY: the blocks' results are in row-major order, so they form the whole result
+    unsafe { NDBox::from_slice_unchecked(len, results) }
+  }
+
+  /// Computes NDSlice::sum_axis() using at most `threads` threads
+  pub fn par_sum_axis<const D: usize>(self, threads: usize) -> NDBox<T, {N - 1}>
+    where Is<{D < N}>: True, T: Clone + Add<Output = T> + Sum + Send + Sync
+  {
+    self.par_reduce_axis::<D, _, _>(threads, NDSlice::sum_pairwise)
+  }
+
+  /// Computes NDSlice::mean_axis() using at most `threads` threads
+  pub fn par_mean_axis<const D: usize>(self, threads: usize) -> NDBox<T, {N - 1}>
+    where Is<{D < N}>: True, T: Float + Send + Sync
+  {
+    let dimension_len = T::from_f64(self.len.0[D] as f64);
+    self.par_reduce_axis::<D, _, _>(threads, |lane| lane.sum_pairwise() / dimension_len)
+  }
+}
+
+impl<'a, T, const N: usize> NDSliceMut<'a, T, N> {
+  /// Applies `f` to each element in place, splitting the slice into blocks
+  /// along the first dimension and updating each block on a separate thread,
+  /// using at most `threads` threads
+  pub fn par_map_inplace<F>(&mut self, threads: usize, f: F) where
+    T: Send,
+    F: Fn(&mut T) + Sync,
+  {
+    let blocks: Vec<_> = self.as_slice().blocks(0, threads).into_iter()
+      .map(|block| {
+        let NDSlice { data, len, stride, .. } = block;
+        // The blocks are disjoint, so each thread has exclusive access to its elements
+        NDSliceMut { data, len, stride, phantom: PhantomData }
+      })
+      .collect();
+    run_blocks(blocks, |mut block: NDSliceMut<T, N>| {
+      for (_, value) in block.iter_mut() {
+        f(value);
+      }
+      Box::new([]) as Box<[()]>
+    });
+  }
+}
+
+/// Computes matrix_product(), splitting the rows of `matrix1` into blocks
+/// and multiplying each block by `matrix2` on a separate thread,
+/// using at most `threads` threads
+pub fn par_matrix_product<'a, 'b, T, U, O>(
+  matrix1: NDSlice<'a, T, 2>,
+  matrix2: NDSlice<'b, U, 2>,
+  threads: usize,
+) -> NDBox<O, 2> where
+  T: Sync,
+  U: Sync,
+  O: Send + Sum<<&'a T as Mul<&'b U>>::Output>,
+  &'a T: Mul<&'b U>,
+{
+  let len1 = matrix1.len;
+  let len2 = matrix2.len;
+  let [length0, inner_length1] = len1.0;
+  let [inner_length2, length1] = len2.0;
+  assert!(
+    inner_length1 == inner_length2,
+    "Cannot multiply matrices of {:?} and {:?}", len1, len2,
+  );
+  let results = run_blocks(matrix1.blocks(0, threads), |block| {
+    matrix_product(block, matrix2).to_box()
+  });
+  // SAFETY: the blocks' rows are in order, so they form the whole result
+  unsafe { NDBox::from_slice_unchecked(Len([length0, length1]), results) }
+}
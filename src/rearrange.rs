@@ -0,0 +1,46 @@
+use super::{Is, NDBox, NDSlice, True};
+
+impl<'a, T: Clone, const N: usize> NDSlice<'a, T, N> {
+  /// Shifts the elements cyclically by `shift` indices along dimension `D`,
+  /// producing a new boxed slice.
+  /// Index i of the result is index (i - shift) mod len[D] of the slice,
+  /// so a positive `shift` moves elements towards the end and wraps the last ones around.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn roll<const D: usize>(self, shift: isize) -> NDBox<T, N> where Is<{D < N}>: True {
+    let len = self.len.0;
+    let dimension_len = len[D] as isize;
+    // Reduce the shift first so subtracting it can't overflow (e.g. for isize::MIN).
+    // If the dimension is empty, there are no elements to shift.
+    let shift = if dimension_len == 0 { 0 } else { shift.rem_euclid(dimension_len) };
+    NDBox::new_with(len, |mut index| {
+      index[D] = ((index[D] as isize + dimension_len - shift) % dimension_len) as usize;
+      self.index(index).clone()
+    })
+  }
+
+  /// Repeats the whole slice `count[D]` times along each dimension D,
+  /// producing a new boxed slice whose length along D is len[D] * count[D]
+  pub fn tile(self, count: [usize; N]) -> NDBox<T, N> {
+    let len = self.len.0;
+    let tiled_len = len.zip(count)
+      .map(|(dimension_len, dimension_count)| dimension_len * dimension_count);
+    NDBox::new_with(tiled_len, |index| {
+      self.index(index.zip(len).map(|(dimension_index, dimension_len)| {
+        dimension_index % dimension_len
+      })).clone()
+    })
+  }
+
+  /// Repeats each element `count` times along dimension `D`,
+  /// producing a new boxed slice whose length along D is len[D] * count.
+  /// For example, repeating [a, b, c] 2 times gives [a, a, b, b, c, c].
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn repeat<const D: usize>(self, count: usize) -> NDBox<T, N> where Is<{D < N}>: True {
+    let mut len = self.len.0;
+    len[D] *= count;
+    NDBox::new_with(len, |mut index| {
+      index[D] /= count;
+      self.index(index).clone()
+    })
+  }
+}
@@ -0,0 +1,151 @@
+use std::collections::VecDeque;
+use std::ops::{Add, Sub};
+use super::{Float, Is, NDBox, NDIntoIterator, NDSlice, True};
+
+/// Computes statistics over a sliding window along dimension `D` of a slice.
+/// Each result is an NDBox with the same length as the slice, except along dimension `D`,
+/// where there is one result for each window (so its length is `width - 1` shorter).
+pub struct Rolling<'a, T, const N: usize, const D: usize> {
+  slice: NDSlice<'a, T, N>,
+  width: usize,
+}
+
+impl<'a, T, const N: usize> NDSlice<'a, T, N> {
+  /// Creates a sliding window of `width` consecutive elements along dimension `D`.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn rolling<const D: usize>(self, width: usize) -> Rolling<'a, T, N, D>
+    where Is<{D < N}>: True
+  {
+    assert!(width > 0, "Rolling window width is 0");
+    Rolling { slice: self, width }
+  }
+}
+
+impl<'a, T, const N: usize, const D: usize> Rolling<'a, T, N, D> where Is<{D < N}>: True {
+  /// Computes the results along each lane of the slice.
+  /// `f` is given a lane and returns an iterator over the results for its windows.
+  fn map_lanes<U, I, F>(&self, f: F) -> NDBox<U, N> where
+    I: Iterator<Item = U>,
+    F: FnMut(NDSlice<'a, T, 1>) -> I,
+  {
+    let Self { slice, width } = *self;
+    let windows = (slice.len()[D] + 1).saturating_sub(width);
+    slice.map_lanes::<D, _, _, _>(windows, f)
+  }
+
+  /// Maps each window (a 1-dimensional slice of `width` elements) according to a function
+  pub fn apply<U, F: FnMut(NDSlice<'a, T, 1>) -> U>(&self, mut f: F) -> NDBox<U, N> {
+    let width = self.width;
+    self.map_lanes(|lane| {
+      lane.windows([width]).map(|(_, window)| f(window)).collect::<Vec<_>>().into_iter()
+    })
+  }
+
+  /// Computes the sum of each window.
+  /// Each sum is computed from the previous one by adding the element entering the window
+  /// and subtracting the element leaving it, so this takes O(1) time per window.
+  pub fn sum(&self) -> NDBox<T, N> where T: Clone + Add<Output = T> + Sub<Output = T> {
+    let width = self.width;
+    self.map_lanes(|lane| {
+      let [lane_len] = lane.len();
+      let mut sum = None;
+      (width..=lane_len).map(move |end| {
+        let next_sum = match sum.take() {
+          None => {
+            let mut values = lane.into_iter().take(width).cloned();
+            let first = values.next().unwrap();
+            values.fold(first, |sum, value| sum + value)
+          },
+          Some(sum) => {
+            sum + lane.index([end - 1]).clone() - lane.index([end - 1 - width]).clone()
+          },
+        };
+        sum = Some(next_sum.clone());
+        next_sum
+      })
+    })
+  }
+
+  /// Computes the mean of each window
+  pub fn mean(&self) -> NDBox<T, N> where T: Float {
+    let width = T::from_f64(self.width as f64);
+    self.sum().map(|sum| sum / width)
+  }
+
+  /// Computes the standard deviation of each window, dividing by `width - ddof`.
+  /// (`ddof` 0 gives the population standard deviation; 1 gives the sample standard deviation.)
+  /// The mean and sum of squared deviations are updated in O(1) time per window
+  /// using Welford's algorithm.
+  pub fn std(&self, ddof: usize) -> NDBox<T, N> where T: Float {
+    let width = self.width;
+    assert!(ddof < width, "ddof {} must be less than rolling window width {}", ddof, width);
+    let width_float = T::from_f64(width as f64);
+    let divisor = T::from_f64((width - ddof) as f64);
+    self.map_lanes(|lane| {
+      let [lane_len] = lane.len();
+      let mut mean = T::ZERO;
+      let mut squared_deviations = T::ZERO;
+      for (count, &value) in lane.into_iter().take(width).enumerate() {
+        // Standard Welford update to add a value
+        let delta = value - mean;
+        mean = mean + delta / T::from_f64((count + 1) as f64);
+        squared_deviations = squared_deviations + delta * (value - mean);
+      }
+      (width..=lane_len).map(move |end| {
+        if end > width {
+          // Replace the value leaving the window with the one entering it
+          let old_value = *lane.index([end - 1 - width]);
+          let new_value = *lane.index([end - 1]);
+          let old_mean = mean;
+          mean = mean + (new_value - old_value) / width_float;
+          squared_deviations = squared_deviations +
+            (new_value - old_value) * (new_value - mean + old_value - old_mean);
+        }
+        // Rounding errors could make the sum of squares slightly negative
+        let variance = squared_deviations / divisor;
+        if variance > T::ZERO { variance.sqrt() } else { T::ZERO }
+      })
+    })
+  }
+
+  /// Computes the extreme value of each window using a monotone deque.
+  /// `replaces(new, old)` should return whether `new` is at least as extreme as `old`,
+  /// in which case `old` can never be the extreme value of a later window.
+  fn extreme<F>(&self, replaces: F) -> NDBox<T, N> where
+    T: Clone,
+    F: Fn(&T, &T) -> bool + Copy,
+  {
+    let width = self.width;
+    self.map_lanes(|lane| {
+      let [lane_len] = lane.len();
+      // Indices of the candidates for the extreme value of this and later windows.
+      // Their values become strictly less extreme from front to back.
+      let mut candidates = VecDeque::new();
+      (0..lane_len).filter_map(move |end| {
+        let value = lane.index([end]);
+        while let Some(&back) = candidates.back() {
+          if !replaces(value, lane.index([back])) {
+            break
+          }
+
+          candidates.pop_back();
+        }
+        candidates.push_back(end);
+        if candidates[0] + width <= end {
+          candidates.pop_front();
+        }
+        (end + 1 >= width).then(|| lane.index([candidates[0]]).clone())
+      })
+    })
+  }
+
+  /// Computes the minimum of each window in O(1) amortized time per window
+  pub fn min(&self) -> NDBox<T, N> where T: Clone + PartialOrd {
+    self.extreme(|new, old| new <= old)
+  }
+
+  /// Computes the maximum of each window in O(1) amortized time per window
+  pub fn max(&self) -> NDBox<T, N> where T: Clone + PartialOrd {
+    self.extreme(|new, old| new >= old)
+  }
+}
@@ -0,0 +1,47 @@
+use super::{Len, NDBox, NDSlice};
+
+impl<'a, T, const N: usize> NDSlice<'a, T, N> {
+  /// Finds the indices of the elements satisfying `predicate`, in row-major order
+  pub fn argwhere<F: FnMut(&T) -> bool>(self, mut predicate: F) -> Vec<[usize; N]> {
+    self.iter().filter(|(_, value)| predicate(value)).map(|(index, _)| index).collect()
+  }
+
+  /// Finds the indices of the elements not equal to T::default() (e.g. 0 or false),
+  /// in row-major order
+  pub fn nonzero(self) -> Vec<[usize; N]> where T: Default + PartialEq {
+    let zero = T::default();
+    self.argwhere(|value| *value != zero)
+  }
+
+  /// Counts the elements satisfying `predicate`
+  pub fn count_where<F: FnMut(&T) -> bool>(self, mut predicate: F) -> usize {
+    self.into_iter().filter(|value| predicate(value)).count()
+  }
+
+  /// Finds the distinct values in the slice, returning them in sorted order
+  /// along with the number of times each one occurs.
+  /// Takes O(size * log(size)) time.
+  pub fn unique(self) -> (NDBox<T, 1>, NDBox<usize, 1>) where T: Clone + Ord {
+    let mut values: Vec<&T> = self.into_iter().collect();
+    values.sort_unstable();
+    let mut unique: Vec<T> = vec![];
+    let mut counts: Vec<usize> = vec![];
+    for value in values {
+      match unique.last() {
+        Some(last) if last == value => *counts.last_mut().unwrap() += 1,
+        _ => {
+          unique.push(value.clone());
+          counts.push(1);
+        },
+      }
+    }
+    let len = Len([unique.len()]);
+    // SAFETY: `unique` and `counts` both have `len` elements
+    unsafe {
+      (
+        NDBox::from_slice_unchecked(len, unique.into_boxed_slice()),
+        NDBox::from_slice_unchecked(len, counts.into_boxed_slice()),
+      )
+    }
+  }
+}
@@ -0,0 +1,168 @@
+use std::cmp::Ordering;
+use super::{Is, NDBox, NDSlice, NDSliceMut, True};
+
+/// Returns the permutation that stably sorts a 1-dimensional slice according to `compare`,
+/// i.e. index i of the result is the index of the ith smallest element
+fn argsort_lane<T, F>(lane: NDSlice<T, 1>, compare: &mut F) -> Vec<usize>
+  where F: FnMut(&T, &T) -> Ordering
+{
+  let [len] = lane.len.0;
+  let mut order: Vec<usize> = (0..len).collect();
+  order.sort_by(|&index1, &index2| compare(lane.index([index1]), lane.index([index2])));
+  order
+}
+
+/// Returns a permutation of a 1-dimensional slice's indices such that
+/// the index of the kth smallest element is at position k,
+/// with the indices of smaller elements before it and larger elements after it
+fn partition_lane<T, F>(lane: NDSlice<T, 1>, k: usize, compare: &mut F) -> Vec<usize>
+  where F: FnMut(&T, &T) -> Ordering
+{
+  let [len] = lane.len.0;
+  assert!(k < len, "Cannot partition at index {} in dimension of len {}", k, len);
+  let mut order: Vec<usize> = (0..len).collect();
+  order.select_nth_unstable_by(k, |&index1, &index2| {
+    compare(lane.index([index1]), lane.index([index2]))
+  });
+  order
+}
+
+/// Rearranges a 1-dimensional slice so index i holds the element that was at `order[i]`.
+/// The permutation is applied in place by swapping the elements around each of its cycles.
+fn permute_lane<T>(mut lane: NDSliceMut<T, 1>, order: &[usize]) {
+  let mut permuted = vec![false; order.len()];
+  for start in 0..order.len() {
+    let mut index = start;
+    while !permuted[index] {
+      permuted[index] = true;
+      let source_index = order[index];
+      if source_index == start {
+        // The element originally at `start` has been swapped into `index`
+        break
+      }
+
+      lane.swap([index], [source_index]);
+      index = source_index;
+    }
+  }
+}
+
+impl<'a, T, const N: usize> NDSlice<'a, T, N> {
+  /// Computes the indices that would sort each lane along dimension `D`.
+  /// Index i along dimension `D` of the result is the index of the ith smallest element
+  /// in that lane. Equal elements keep their relative order.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn argsort_axis<const D: usize>(self) -> NDBox<usize, N>
+    where Is<{D < N}>: True, T: Ord
+  {
+    self.argsort_axis_by::<D, _>(T::cmp)
+  }
+
+  /// Like NDSlice::argsort_axis(), but orders the elements according to `compare`
+  pub fn argsort_axis_by<const D: usize, F>(self, mut compare: F) -> NDBox<usize, N>
+    where Is<{D < N}>: True, F: FnMut(&T, &T) -> Ordering
+  {
+    self.map_lanes::<D, _, _, _>(self.len.0[D], |lane| argsort_lane(lane, &mut compare))
+  }
+
+  /// Finds the indices of the `k` largest elements in each lane along dimension `D`,
+  /// in order from largest to smallest (with ties broken by lower index).
+  /// The result has length `k` along dimension `D`.
+  /// The elements themselves can be copied using NDSlice::take_along_axis().
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn top_k<const D: usize>(self, k: usize) -> NDBox<usize, N>
+    where Is<{D < N}>: True, T: Ord
+  {
+    self.top_k_by::<D, _>(k, T::cmp)
+  }
+
+  /// Like NDSlice::top_k(), but orders the elements according to `compare`
+  pub fn top_k_by<const D: usize, F>(self, k: usize, mut compare: F) -> NDBox<usize, N>
+    where Is<{D < N}>: True, F: FnMut(&T, &T) -> Ordering
+  {
+    let dimension_len = self.len.0[D];
+    assert!(k <= dimension_len, "Cannot take top {} of dimension of len {}", k, dimension_len);
+    self.map_lanes::<D, _, _, _>(k, |lane| {
+      // Order the indices from largest to smallest element, breaking ties by index
+      let mut compare_indices = |&index1: &usize, &index2: &usize| {
+        compare(lane.index([index2]), lane.index([index1])).then(index1.cmp(&index2))
+      };
+      let mut order: Vec<usize> = (0..dimension_len).collect();
+      // Move the top k indices to the front, then sort only those
+      if k > 0 && k < dimension_len {
+        order.select_nth_unstable_by(k - 1, &mut compare_indices);
+      }
+      order.truncate(k);
+      order.sort_by(compare_indices);
+      order
+    })
+  }
+}
+
+impl<'a, T, const N: usize> NDSliceMut<'a, T, N> {
+  /// Sorts each lane along dimension `D` in place.
+  /// The sort is stable, and works even if the lanes aren't contiguous in memory.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn sort_axis_mut<const D: usize>(&mut self) where Is<{D < N}>: True, T: Ord {
+    self.sort_axis_by_mut::<D, _>(T::cmp);
+  }
+
+  /// Like NDSliceMut::sort_axis_mut(), but orders the elements according to `compare`
+  pub fn sort_axis_by_mut<const D: usize, F>(&mut self, mut compare: F)
+    where Is<{D < N}>: True, F: FnMut(&T, &T) -> Ordering
+  {
+    for (_, lane) in self.lanes_mut::<D>() {
+      // Compute the sorted order before moving any elements,
+      // so the current lane is left unchanged if `compare` panics
+      // (lanes already sorted stay sorted)
+      let order = argsort_lane(lane.as_slice(), &mut compare);
+      permute_lane(lane, &order);
+    }
+  }
+
+  /// Partially sorts each lane along dimension `D` in place (see <[T]>::select_nth_unstable()),
+  /// moving the kth smallest element to index `k`,
+  /// smaller elements before it, and larger elements after it.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn partition_axis_mut<const D: usize>(&mut self, k: usize) where Is<{D < N}>: True, T: Ord {
+    self.partition_axis_by_mut::<D, _>(k, T::cmp);
+  }
+
+  /// Like NDSliceMut::partition_axis_mut(), but orders the elements according to `compare`
+  pub fn partition_axis_by_mut<const D: usize, F>(&mut self, k: usize, mut compare: F)
+    where Is<{D < N}>: True, F: FnMut(&T, &T) -> Ordering
+  {
+    for (_, lane) in self.lanes_mut::<D>() {
+      // As in sort_axis_by_mut(), only the current lane is left unchanged if `compare` panics
+      let order = partition_lane(lane.as_slice(), k, &mut compare);
+      permute_lane(lane, &order);
+    }
+  }
+}
+
+impl<'a, T: PartialOrd> NDSlice<'a, T, 1> {
+  /// Finds the first index where `value` could be inserted into this sorted slice
+  /// while keeping it sorted, i.e. the number of elements less than `value`.
+  /// Uses binary search, so it takes O(log(len)) time.
+  pub fn searchsorted(self, value: &T) -> usize {
+    self.partition_point(|element| element < value)
+  }
+
+  /// Like NDSlice::searchsorted(), but finds the last index where `value` could be inserted,
+  /// i.e. the number of elements less than or equal to `value`
+  pub fn searchsorted_right(self, value: &T) -> usize {
+    self.partition_point(|element| element <= value)
+  }
+
+  /// Returns the first index where `predicate` is false,
+  /// assuming it is true for a prefix of the slice and false for the rest
+  fn partition_point<F: Fn(&T) -> bool>(self, predicate: F) -> usize {
+    let [len] = self.len.0;
+    let (mut low, mut high) = (0, len);
+    while low < high {
+      let middle = low + (high - low) / 2;
+      if predicate(self.index([middle])) { low = middle + 1 } else { high = middle }
+    }
+    low
+  }
+}
@@ -0,0 +1,204 @@
+use std::cmp::Ordering;
+use std::iter::Sum;
+use std::ops::Add;
+use super::{pairwise_sum, Float, Is, NDBox, NDIntoIterator, NDSlice, True};
+
+/// How quantile_axis() chooses a value when the quantile lies between two elements,
+/// following numpy's conventions.
+/// If the elements in sorted order are x[0], ..., x[n - 1], quantile q lies at position
+/// q * (n - 1), between x[i] and x[j] (where i and j round this position down and up).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+  /// Interpolates linearly between x[i] and x[j]
+  Linear,
+  /// Uses x[i]
+  Lower,
+  /// Uses x[j]
+  Higher,
+  /// Uses whichever of x[i] and x[j] is closer (x[i] if i is even and they are equally close)
+  Nearest,
+  /// Uses the average of x[i] and x[j]
+  Midpoint,
+}
+
+fn compare_floats<T: Float>(value1: &T, value2: &T) -> Ordering {
+  value1.partial_cmp(value2).expect("Cannot compute quantile of NaN")
+}
+
+/// Computes the mean of a 1-dimensional slice
+fn lane_mean<T: Float>(lane: NDSlice<T, 1>) -> T {
+  let [len] = lane.len.0;
+  lane.sum_pairwise() / T::from_f64(len as f64)
+}
+
+/// Computes the `q` quantile of a 1-dimensional slice.
+/// Uses a selection algorithm, so this takes O(len) time rather than O(len * log(len)).
+fn lane_quantile<T: Float>(lane: NDSlice<T, 1>, q: f64, interpolation: Interpolation) -> T {
+  let mut values: Vec<T> = lane.into_iter().copied().collect();
+  assert!(!values.is_empty(), "Cannot compute quantile of empty dimension");
+  let position = q * (values.len() - 1) as f64;
+  // Rounds down, since `position` is nonnegative
+  let lower_index = position as usize;
+  let fraction = position - lower_index as f64;
+  let (_, &mut lower, higher_values) =
+    values.select_nth_unstable_by(lower_index, compare_floats);
+  let higher =
+    if fraction > 0.0 {
+      // The next element in sorted order is the smallest of the larger elements
+      higher_values.iter().copied().min_by(compare_floats).unwrap()
+    }
+    else { lower };
+  match interpolation {
+    Interpolation::Linear => lower + (higher - lower) * T::from_f64(fraction),
+    Interpolation::Lower => lower,
+    Interpolation::Higher => higher,
+    Interpolation::Nearest => {
+      let use_lower =
+        if fraction == 0.5 { lower_index & 1 == 0 }
+        else { fraction < 0.5 };
+      if use_lower { lower } else { higher }
+    },
+    Interpolation::Midpoint => (lower + higher) / T::from_f64(2.0),
+  }
+}
+
+impl<'a, T, const N: usize> NDSlice<'a, T, N> {
+  /// Computes the sum of the elements along dimension `D` (using NDSlice::sum_pairwise()),
+  /// producing a new boxed slice without dimension `D`.
+  /// The dimension is required to be a constant so it can be checked at compile time.
+  pub fn sum_axis<const D: usize>(self) -> NDBox<T, {N - 1}>
+    where Is<{D < N}>: True, T: Clone + Add<Output = T> + Sum
+  {
+    self.reduce_axis::<D, _, _>(NDSlice::sum_pairwise)
+  }
+
+  /// Computes the mean of the elements along dimension `D` (see sum_axis())
+  pub fn mean_axis<const D: usize>(self) -> NDBox<T, {N - 1}>
+    where Is<{D < N}>: True, T: Float
+  {
+    self.reduce_axis::<D, _, _>(lane_mean)
+  }
+
+  /// Computes the variance of the elements along dimension `D` (see sum_axis()),
+  /// dividing the sum of squared deviations by `len - ddof`.
+  /// (`ddof` 0 gives the population variance; 1 gives the unbiased sample variance.)
+  pub fn var_axis<const D: usize>(self, ddof: usize) -> NDBox<T, {N - 1}>
+    where Is<{D < N}>: True, T: Float
+  {
+    let dimension_len = self.len.0[D];
+    assert!(
+      ddof < dimension_len,
+      "ddof {} must be less than dimension of len {}", ddof, dimension_len,
+    );
+    let divisor = T::from_f64((dimension_len - ddof) as f64);
+    self.reduce_axis::<D, _, _>(|lane| {
+      // Computing the mean first avoids the cancellation in E[x^2] - E[x]^2
+      let mean = lane_mean(lane);
+      let squared_deviations = lane.into_iter().map(|&value| {
+        let deviation = value - mean;
+        deviation * deviation
+      });
+      pairwise_sum(squared_deviations) / divisor
+    })
+  }
+
+  /// Computes the standard deviation of the elements along dimension `D` (see var_axis())
+  pub fn std_axis<const D: usize>(self, ddof: usize) -> NDBox<T, {N - 1}>
+    where Is<{D < N}>: True, T: Float
+  {
+    self.var_axis::<D>(ddof).map(T::sqrt)
+  }
+
+  /// Computes the `q` quantile (between 0 and 1) of the elements along dimension `D`
+  /// (see sum_axis()). `interpolation` determines the result when the quantile
+  /// lies between two elements. Panics if the slice contains NaN.
+  pub fn quantile_axis<const D: usize>(self, q: f64, interpolation: Interpolation)
+    -> NDBox<T, {N - 1}>
+    where Is<{D < N}>: True, T: Float
+  {
+    assert!((0.0..=1.0).contains(&q), "Quantile {} is not between 0 and 1", q);
+    self.reduce_axis::<D, _, _>(|lane| lane_quantile(lane, q, interpolation))
+  }
+
+  /// Computes the median of the elements along dimension `D` (see quantile_axis()).
+  /// If a lane has an even number of elements, the two middle elements are averaged.
+  pub fn median_axis<const D: usize>(self) -> NDBox<T, {N - 1}>
+    where Is<{D < N}>: True, T: Float
+  {
+    self.quantile_axis::<D>(0.5, Interpolation::Linear)
+  }
+
+  /// Counts the elements in each of `bins` equal-width bins spanning `range`,
+  /// returning the counts along with the `bins + 1` bin edges.
+  /// Each bin includes its left edge, and the last bin also includes its right edge.
+  /// Elements outside `range` (and NaNs) are not counted.
+  pub fn histogram(self, bins: usize, range: (T, T)) -> (NDBox<usize, 1>, NDBox<T, 1>)
+    where T: Float
+  {
+    let (low, high) = range;
+    assert!(bins > 0, "Histogram must have at least 1 bin");
+    assert!(low < high, "Histogram range {:?} is empty", range);
+    let width = high - low;
+    let bins_float = T::from_f64(bins as f64);
+    let edges = NDBox::new_with([bins + 1], |[bin]| {
+      if bin == bins { high }
+      else { low + width * T::from_f64(bin as f64) / bins_float }
+    });
+    let mut counts = NDBox::new_with([bins], |_| 0);
+    for &value in self {
+      if !(low <= value && value <= high) {
+        continue
+      }
+
+      let mut bin = ((value - low) / width * bins_float).to_f64() as usize;
+      bin = bin.min(bins - 1);
+      // Rounding errors could put the value in an adjacent bin, so check against the edges
+      if value < edges[[bin]] {
+        bin -= 1;
+      }
+      else if bin + 1 < bins && value >= edges[[bin + 1]] {
+        bin += 1;
+      }
+      counts[[bin]] += 1;
+    }
+    (counts, edges)
+  }
+}
+
+/// Computes the covariance matrix of a set of observations,
+/// where each row is an observation and each column is a variable.
+/// Index [i, j] of the result is the covariance of variables i and j,
+/// dividing the sum of products of deviations by `observations - ddof`.
+pub fn covariance<T: Float>(observations: NDSlice<T, 2>, ddof: usize) -> NDBox<T, 2> {
+  let [count, variables] = observations.len.0;
+  assert!(
+    ddof < count,
+    "ddof {} must be less than number of observations {}", ddof, count,
+  );
+  let means = observations.mean_axis::<0>();
+  let deviations = NDBox::new_with([count, variables], |[observation, variable]| {
+    observations[[observation, variable]] - means[[variable]]
+  });
+  let divisor = T::from_f64((count - ddof) as f64);
+  NDBox::new_with([variables, variables], |[variable1, variable2]| {
+    let products = (0..count).map(|observation| {
+      deviations[[observation, variable1]] * deviations[[observation, variable2]]
+    });
+    pairwise_sum(products) / divisor
+  })
+}
+
+/// Computes the Pearson correlation coefficient matrix of a set of observations
+/// (see covariance()). Variables with no variance have NaN correlations.
+pub fn correlation<T: Float>(observations: NDSlice<T, 2>) -> NDBox<T, 2> {
+  let covariances = covariance(observations, 0);
+  let [_, variables] = observations.len.0;
+  NDBox::new_with([variables, variables], |[variable1, variable2]| {
+    let variances = covariances[[variable1, variable1]] * covariances[[variable2, variable2]];
+    let correlation = covariances[[variable1, variable2]] / variances.sqrt();
+    // Rounding errors could push the correlation slightly outside [-1, 1]
+    if correlation > T::ONE { T::ONE }
+    else if correlation < -T::ONE { -T::ONE }
+    else { correlation }
+  })
+}
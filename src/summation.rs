@@ -0,0 +1,22 @@
+use std::iter::Sum;
+use std::ops::Add;
+use super::{compensated_sum, pairwise_sum, Float, NDSlice};
+
+impl<'a, T, const N: usize> NDSlice<'a, T, N> {
+  /// Sums the elements using pairwise summation, whose rounding error grows with
+  /// O(log(size)) rather than O(size) as in naive summation.
+  /// The elements are added in an order that depends only on the slice's length
+  /// (not its memory layout), so equal slices always produce the same sum.
+  /// This is the summation used by reductions such as sum_axis() and mean_axis().
+  pub fn sum_pairwise(self) -> T where T: Clone + Add<Output = T> + Sum {
+    pairwise_sum(self.into_iter().cloned())
+  }
+
+  /// Sums the elements using compensated (Kahan-Babuska-Neumaier) summation,
+  /// whose rounding error (to first order) doesn't grow with the number of elements.
+  /// This is slower than NDSlice::sum_pairwise(), but more accurate.
+  /// Like NDSlice::sum_pairwise(), the result doesn't depend on the memory layout.
+  pub fn sum_kahan(self) -> T where T: Float {
+    compensated_sum(self.into_iter().copied())
+  }
+}
@@ -0,0 +1,477 @@
+use std::fmt::{self, Debug, Formatter};
+use std::iter;
+use std::mem::MaybeUninit;
+use std::ops::{Index as IndexOp, IndexMut};
+use super::{Bounds, Index, IndexIterator, Len, NDBox, NDError, NDSlice, Stride};
+
+/// An owned N-dimensional array stored in blocked tiles rather than in row-major order.
+/// The array is split into tiles of `tile_len` (tiles at the end of a dimension may be shorter).
+/// Each tile's elements are contiguous in memory, so traversing a small neighborhood
+/// along any dimension stays within a few tiles, unlike an NDBox where
+/// neighbors along the first dimension are a whole row of elements apart.
+#[derive(Clone)]
+pub struct NDTiled<T, const N: usize> {
+  /// The elements, tile by tile. The tiles are in row-major order by tile index,
+  /// and the elements within each tile are in row-major order.
+  data: Box<[T]>,
+  len: Len<N>,
+  /// The length of each full tile
+  tile_len: [usize; N],
+  /// The row-major strides of `len` (the products of the later dimensions' lengths),
+  /// precomputed so finding an element's offset takes O(N) time
+  stride: Stride<N>,
+}
+
+/// A shared view of an NDTiled, restricted by NDTiled::slice()
+pub struct NDTiledSlice<'a, T, const N: usize> {
+  tiled: &'a NDTiled<T, N>,
+  /// The index in `tiled` of index [0, ..., 0] in the slice
+  start: [usize; N],
+  /// The number of indices in `tiled` to advance by one in each direction
+  step: [usize; N],
+  len: Len<N>,
+}
+
+/// A mutable view of an NDTiled, restricted by NDTiled::slice_mut()
+pub struct NDTiledSliceMut<'a, T, const N: usize> {
+  tiled: &'a mut NDTiled<T, N>,
+  /// The index in `tiled` of index [0, ..., 0] in the slice
+  start: [usize; N],
+  /// The number of indices in `tiled` to advance by one in each direction
+  step: [usize; N],
+  len: Len<N>,
+}
+
+/// Iterates over the indices of an NDTiled in the order their elements are stored
+fn tiled_indices<const N: usize>(len: Len<N>, tile_len: [usize; N])
+  -> impl Iterator<Item = [usize; N]>
+{
+  // Rounds up without computing `dimension_len + dimension_tile_len - 1`,
+  // which could overflow for very long tiles (e.g. usize::MAX to not tile a dimension)
+  let tiles_len = Len(len.0.zip(tile_len).map(|(dimension_len, dimension_tile_len)| {
+    dimension_len / dimension_tile_len + (dimension_len % dimension_tile_len != 0) as usize
+  }));
+  IndexIterator::new(tiles_len).take(tiles_len.size()).flat_map(move |tile| {
+    let tile_start = tile.zip(tile_len).map(|(tile_index, dimension_tile_len)| {
+      tile_index * dimension_tile_len
+    });
+    let mut this_tile_len = Len(tile_len);
+    for (dimension, dimension_tile_len) in this_tile_len.0.iter_mut().enumerate() {
+      *dimension_tile_len = (*dimension_tile_len).min(len.0[dimension] - tile_start[dimension]);
+    }
+    IndexIterator::new(this_tile_len).take(this_tile_len.size()).map(move |tile_index| {
+      let mut index = tile_start;
+      for (dimension_index, offset) in iter::zip(&mut index, tile_index) {
+        *dimension_index += offset;
+      }
+      index
+    })
+  })
+}
+
+/// Computes the index in an NDTiled of an index in a slice of it
+/// that starts at `start` and advances by `step`
+fn tiled_index<const N: usize>(start: [usize; N], step: [usize; N], index: [usize; N])
+  -> [usize; N]
+{
+  let mut tiled_index = start;
+  for (dimension, dimension_index) in tiled_index.iter_mut().enumerate() {
+    *dimension_index += index[dimension] * step[dimension];
+  }
+  tiled_index
+}
+
+/// Computes the position in an NDTiled's `data` of the element at an in-bounds index.
+/// A free function so NDTiledSliceMut::iter_mut() can use it while `data` is borrowed mutably.
+fn tiled_offset<const N: usize>(
+  len: Len<N>,
+  tile_len: [usize; N],
+  stride: Stride<N>,
+  index: [usize; N],
+) -> usize {
+  // The tiles before this one contain every element before this tile's start
+  // along dimension 0, then every element in this row of tiles before its start
+  // along dimension 1, etc.
+  let mut tiles_offset = 0;
+  let mut tile_offset = 0;
+  let mut outer_tile_size = 1;
+  for dimension in 0..N {
+    let dimension_tile_len = tile_len[dimension];
+    let dimension_index = index[dimension];
+    let tile_start = dimension_index / dimension_tile_len * dimension_tile_len;
+    let this_tile_len = dimension_tile_len.min(len.0[dimension] - tile_start);
+    tiles_offset += outer_tile_size * tile_start * stride.0[dimension];
+    outer_tile_size *= this_tile_len;
+    tile_offset = tile_offset * this_tile_len + (dimension_index - tile_start);
+  }
+  tiles_offset + tile_offset
+}
+
+impl<T, const N: usize> NDTiled<T, N> {
+  /// Creates a new NDTiled of the specified length, split into tiles of `tile_len`,
+  /// initializing each element by calling the initializer with its index.
+  /// The initializer is called on the indices in memory order (tile by tile).
+  pub fn new_with<F>(len: [usize; N], tile_len: [usize; N], init: F) -> Self
+    where F: FnMut([usize; N]) -> T
+  {
+    assert!(
+      tile_len.iter().all(|&dimension_tile_len| dimension_tile_len > 0),
+      "Tile length {:?} must be nonzero in every dimension", tile_len,
+    );
+    let len = Len(len);
+    let size = len.size();
+    let data: Box<[T]> = tiled_indices(len, tile_len).map(init).collect();
+    debug_assert_eq!(data.len(), size);
+    Self { data, len, tile_len, stride: len.default_stride() }
+  }
+
+  /// Copies an N-dimensional slice into tiles of `tile_len`
+  pub fn from_slice(slice: NDSlice<T, N>, tile_len: [usize; N]) -> Self where T: Clone {
+    Self::new_with(slice.len.0, tile_len, |index| slice[index].clone())
+  }
+
+  /// Moves the elements of an NDBox into tiles of `tile_len`
+  pub fn from_box(values: NDBox<T, N>, tile_len: [usize; N]) -> Self {
+    let len = values.len;
+    let mut tiled = NDTiled::new_with(len.0, tile_len, |_| MaybeUninit::uninit());
+    for (index, value) in values.iter_owned() {
+      let offset = tiled.offset(index);
+      tiled.data[offset].write(value);
+    }
+    let NDTiled { data, stride, .. } = tiled;
+    // SAFETY: each index has a different offset, so every element was written to
+    let data = unsafe { data.assume_init() };
+    Self { data, len, tile_len, stride }
+  }
+
+  /// Copies the elements into a new NDBox (in row-major order)
+  pub fn to_box(&self) -> NDBox<T, N> where T: Clone {
+    NDBox::new_with(self.len.0, |index| self[index].clone())
+  }
+
+  /// Moves the elements into a new NDBox (in row-major order)
+  pub fn into_box(self) -> NDBox<T, N> {
+    let Self { data, len, tile_len, .. } = self;
+    let mut values = NDBox::<T, N>::new_uninit(len.0);
+    for (index, value) in tiled_indices(len, tile_len).zip(data.into_vec()) {
+      values[index].write(value);
+    }
+    // SAFETY: each index appears once in `tiled_indices()`, so every element was written to
+    unsafe { values.assume_init() }
+  }
+
+  /// Returns the number of indices along each dimension
+  pub fn len(&self) -> [usize; N] {
+    self.len.0
+  }
+
+  /// Returns the length of each full tile
+  pub fn tile_len(&self) -> [usize; N] {
+    self.tile_len
+  }
+
+  /// Computes the position in `data` of the element at an in-bounds index
+  fn offset(&self, index: [usize; N]) -> usize {
+    let Self { len, tile_len, stride, .. } = *self;
+    tiled_offset(len, tile_len, stride, index)
+  }
+
+  /// Accesses the element at the given index if it is in bounds, else returns None
+  pub fn get(&self, index: [usize; N]) -> Option<&T> {
+    self.as_slice().get(index)
+  }
+
+  /// Like NDTiled::get(), but returns a mutable reference
+  pub fn get_mut(&mut self, index: [usize; N]) -> Option<&mut T> {
+    let offset = self.as_slice().offset(index)?;
+    Some(&mut self.data[offset])
+  }
+
+  /// Iterates over all elements (in row-major order) along with their index
+  pub fn iter(&self) -> impl Iterator<Item = ([usize; N], &T)> {
+    self.as_slice().iter()
+  }
+
+  /// Like NDTiled::iter(), but gives mutable references to the elements
+  pub fn iter_mut(&mut self) -> impl Iterator<Item = ([usize; N], &mut T)> {
+    self.as_mut().into_iter_mut()
+  }
+
+  /// Like NDTiled::iter(), but visits the elements in memory order (tile by tile),
+  /// which is faster than row-major order
+  pub fn iter_memory_order(&self) -> impl Iterator<Item = ([usize; N], &T)> {
+    tiled_indices(self.len, self.tile_len).zip(self.data.iter())
+  }
+
+  /// Like NDTiled::iter_memory_order(), but gives mutable references to the elements
+  pub fn iter_memory_order_mut(&mut self) -> impl Iterator<Item = ([usize; N], &mut T)> {
+    tiled_indices(self.len, self.tile_len).zip(self.data.iter_mut())
+  }
+
+  /// Creates a shared view of the whole array
+  pub fn as_slice(&self) -> NDTiledSlice<T, N> {
+    NDTiledSlice { tiled: self, start: [0; N], step: [1; N], len: self.len }
+  }
+
+  /// Creates a mutable view of the whole array
+  pub fn as_mut(&mut self) -> NDTiledSliceMut<T, N> {
+    let len = self.len;
+    NDTiledSliceMut { tiled: self, start: [0; N], step: [1; N], len }
+  }
+
+  /// Restricts the array to a slice along each dimension (see NDSlice::slice())
+  pub fn slice(&self, bounds: [Bounds; N]) -> NDTiledSlice<T, N> {
+    self.as_slice().slice(bounds)
+  }
+
+  /// Like NDTiled::slice(), but returns a mutable view
+  pub fn slice_mut(&mut self, bounds: [Bounds; N]) -> NDTiledSliceMut<T, N> {
+    self.as_mut().into_slice_mut(bounds).unwrap_or_else(|error| panic!("{}", error))
+  }
+}
+
+impl<'a, T, const N: usize> NDTiledSlice<'a, T, N> {
+  /// Returns the number of indices along each dimension
+  pub fn len(self) -> [usize; N] {
+    self.len.0
+  }
+
+  /// Computes the position in the NDTiled's `data` of an index in the slice,
+  /// or returns None if the index is out of bounds
+  fn offset(self, index: [usize; N]) -> Option<usize> {
+    let in_bounds = iter::zip(index, self.len.0)
+      .all(|(dimension_index, dimension_len)| dimension_index < dimension_len);
+    if !in_bounds {
+      return None
+    }
+
+    Some(self.tiled.offset(tiled_index(self.start, self.step, index)))
+  }
+
+  /// Like NDTiledSlice::offset(), but panics if the index is out of bounds
+  fn index_offset(self, index: [usize; N]) -> usize {
+    self.offset(index).unwrap_or_else(|| {
+      panic!("{:?} out of bounds for {:?}", Index(index), self.len)
+    })
+  }
+
+  /// Accesses the element at the given index if it is in bounds, else returns None
+  pub fn get(self, index: [usize; N]) -> Option<&'a T> {
+    let offset = self.offset(index)?;
+    Some(&self.tiled.data[offset])
+  }
+
+  /// Like ops::Index but returns a reference with the slice lifetime 'a
+  /// rather than the lifetime of the borrow (&self)
+  pub fn index(self, index: [usize; N]) -> &'a T {
+    &self.tiled.data[self.index_offset(index)]
+  }
+
+  /// Iterates over all elements (in row-major order) along with their index
+  pub fn iter(self) -> impl Iterator<Item = ([usize; N], &'a T)> {
+    let len = self.len;
+    IndexIterator::new(len).take(len.size()).map(move |index| (index, self.index(index)))
+  }
+
+  /// Restricts the slice further along each dimension (see NDSlice::slice())
+  pub fn slice(self, bounds: [Bounds; N]) -> Self {
+    self.try_slice(bounds).unwrap_or_else(|error| panic!("{}", error))
+  }
+
+  /// Like NDTiledSlice::slice(), but returns an error if the bounds are out of range
+  pub fn try_slice(self, bounds: [Bounds; N]) -> Result<Self, NDError> {
+    let Self { tiled, mut start, mut step, len } = self;
+    let (slice_start, len) = len.slice(bounds)?;
+    for dimension in 0..N {
+      start[dimension] += slice_start.0[dimension] * step[dimension];
+      step[dimension] *= bounds[dimension].step;
+    }
+    Ok(Self { tiled, start, step, len })
+  }
+
+  /// Copies the elements into a new NDBox (in row-major order)
+  pub fn to_box(self) -> NDBox<T, N> where T: Clone {
+    NDBox::new_with(self.len.0, |index| self.index(index).clone())
+  }
+}
+
+impl<'a, T, const N: usize> NDTiledSliceMut<'a, T, N> {
+  /// Returns the number of indices along each dimension
+  pub fn len(&self) -> [usize; N] {
+    self.len.0
+  }
+
+  /// Creates a shared view of the same elements
+  pub fn as_slice(&self) -> NDTiledSlice<'_, T, N> {
+    let Self { ref tiled, start, step, len } = *self;
+    NDTiledSlice { tiled, start, step, len }
+  }
+
+  /// Reborrows the view for a shorter lifetime, so it can be consumed
+  fn reborrow(&mut self) -> NDTiledSliceMut<'_, T, N> {
+    let Self { ref mut tiled, start, step, len } = *self;
+    NDTiledSliceMut { tiled, start, step, len }
+  }
+
+  /// Accesses the element at the given index if it is in bounds, else returns None
+  pub fn get(&self, index: [usize; N]) -> Option<&T> {
+    self.as_slice().get(index)
+  }
+
+  /// Like NDTiledSliceMut::get(), but returns a mutable reference
+  pub fn get_mut(&mut self, index: [usize; N]) -> Option<&mut T> {
+    let offset = self.as_slice().offset(index)?;
+    Some(&mut self.tiled.data[offset])
+  }
+
+  /// Iterates over all elements (in row-major order) along with their index
+  pub fn iter(&self) -> impl Iterator<Item = ([usize; N], &T)> {
+    self.as_slice().iter()
+  }
+
+  /// Like NDTiledSliceMut::iter(), but gives mutable references to the elements
+  pub fn iter_mut(&mut self) -> impl Iterator<Item = ([usize; N], &mut T)> {
+    self.reborrow().into_iter_mut()
+  }
+
+  /// Like NDTiledSliceMut::iter_mut(), but consumes the view
+  /// so the references have its lifetime 'a
+  fn into_iter_mut(self) -> impl Iterator<Item = ([usize; N], &'a mut T)> {
+    let Self { tiled, start, step, len } = self;
+    let NDTiled { len: tiled_len, tile_len, stride, .. } = *tiled;
+    let data = tiled.data.as_mut_ptr();
+    IndexIterator::new(len).take(len.size()).map(move |index| {
+      let offset = tiled_offset(tiled_len, tile_len, stride, tiled_index(start, step, index));
+      // SAFETY: each index is in bounds and (since every step is nonzero) has a different offset,
+      // so the mutable references are valid and don't overlap
+      (index, unsafe { &mut *data.add(offset) })
+    })
+  }
+
+  /// Restricts the view further along each dimension (see NDSlice::slice())
+  pub fn slice_mut(&mut self, bounds: [Bounds; N]) -> NDTiledSliceMut<'_, T, N> {
+    self.try_slice_mut(bounds).unwrap_or_else(|error| panic!("{}", error))
+  }
+
+  /// Like NDTiledSliceMut::slice_mut(), but returns an error if the bounds are out of range
+  pub fn try_slice_mut(&mut self, bounds: [Bounds; N])
+    -> Result<NDTiledSliceMut<'_, T, N>, NDError>
+  {
+    self.reborrow().into_slice_mut(bounds)
+  }
+
+  /// Like NDTiledSliceMut::try_slice_mut(), but consumes the view
+  /// so the result has its lifetime 'a
+  fn into_slice_mut(self, bounds: [Bounds; N]) -> Result<Self, NDError> {
+    let NDTiledSlice { start, step, len, .. } = self.as_slice().try_slice(bounds)?;
+    Ok(Self { tiled: self.tiled, start, step, len })
+  }
+
+  /// Copies the elements into a new NDBox (in row-major order)
+  pub fn to_box(&self) -> NDBox<T, N> where T: Clone {
+    self.as_slice().to_box()
+  }
+}
+
+impl<T, const N: usize> Clone for NDTiledSlice<'_, T, N> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<T, const N: usize> Copy for NDTiledSlice<'_, T, N> {}
+
+impl<T, const N: usize> IndexOp<[usize; N]> for NDTiled<T, N> {
+  type Output = T;
+
+  fn index(&self, index: [usize; N]) -> &T {
+    self.as_slice().index(index)
+  }
+}
+
+impl<T, const N: usize> IndexMut<[usize; N]> for NDTiled<T, N> {
+  fn index_mut(&mut self, index: [usize; N]) -> &mut T {
+    let offset = self.as_slice().index_offset(index);
+    &mut self.data[offset]
+  }
+}
+
+impl<T, const N: usize> IndexOp<[usize; N]> for NDTiledSlice<'_, T, N> {
+  type Output = T;
+
+  fn index(&self, index: [usize; N]) -> &T {
+    (*self).index(index)
+  }
+}
+
+impl<T, const N: usize> IndexOp<[usize; N]> for NDTiledSliceMut<'_, T, N> {
+  type Output = T;
+
+  fn index(&self, index: [usize; N]) -> &T {
+    self.as_slice().index(index)
+  }
+}
+
+impl<T, const N: usize> IndexMut<[usize; N]> for NDTiledSliceMut<'_, T, N> {
+  fn index_mut(&mut self, index: [usize; N]) -> &mut T {
+    let offset = self.as_slice().index_offset(index);
+    &mut self.tiled.data[offset]
+  }
+}
+
+/// Displays the length and the elements in row-major order
+impl<T: Debug, const N: usize> Debug for NDTiledSlice<'_, T, N> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    let values: Vec<_> = self.iter().map(|(_, value)| value).collect();
+    f.debug_struct("NDTiledSlice").field("len", &self.len.0).field("values", &values).finish()
+  }
+}
+
+/// Displays the length, the tile length, and the elements in row-major order
+impl<T: Debug, const N: usize> Debug for NDTiled<T, N> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    let values: Vec<_> = self.iter().map(|(_, value)| value).collect();
+    f.debug_struct("NDTiled")
+      .field("len", &self.len.0)
+      .field("tile_len", &self.tile_len)
+      .field("values", &values)
+      .finish()
+  }
+}
+
+impl<T: Debug, const N: usize> Debug for NDTiledSliceMut<'_, T, N> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    self.as_slice().fmt(f)
+  }
+}
+
+/// Two slices are equal iff their lengths and all corresponding values are equal
+/// (regardless of how the arrays are tiled)
+impl<T, U, const N: usize> PartialEq<NDTiledSlice<'_, U, N>> for NDTiledSlice<'_, T, N>
+  where T: PartialEq<U>
+{
+  fn eq(&self, other: &NDTiledSlice<U, N>) -> bool {
+    let values_eq = || iter::zip(self.iter(), other.iter()).all(|((_, lhs), (_, rhs))| lhs == rhs);
+    self.len == other.len && values_eq()
+  }
+}
+
+impl<T: Eq, const N: usize> Eq for NDTiledSlice<'_, T, N> {}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<NDTiled<U, N>> for NDTiled<T, N> {
+  fn eq(&self, other: &NDTiled<U, N>) -> bool {
+    self.as_slice() == other.as_slice()
+  }
+}
+
+impl<T: Eq, const N: usize> Eq for NDTiled<T, N> {}
+
+impl<T, U, const N: usize> PartialEq<NDTiledSliceMut<'_, U, N>> for NDTiledSliceMut<'_, T, N>
+  where T: PartialEq<U>
+{
+  fn eq(&self, other: &NDTiledSliceMut<U, N>) -> bool {
+    self.as_slice() == other.as_slice()
+  }
+}
+
+impl<T: Eq, const N: usize> Eq for NDTiledSliceMut<'_, T, N> {}
@@ -2,7 +2,11 @@
 // Since `True` is only implemented for `Is<true>`,
 // the constraint `Is<X>: True` requires `X` to be true.
 
-use std::ptr::NonNull;
+use std::iter::{self, Sum};
+use std::mem::{self, MaybeUninit};
+use std::ops::Add;
+use std::ptr::{self, NonNull};
+use super::Float;
 
 pub enum Is<const B: bool> {}
 
@@ -15,6 +19,40 @@ pub unsafe fn as_mut<T>(value: &T) -> &mut T {
   NonNull::from(value).as_mut()
 }
 
+/// Drops the first `initialized` elements of `data` when it is dropped,
+/// so a panic while initializing `data` doesn't leak the elements already written
+struct InitGuard<'a, T> {
+  data: &'a mut [MaybeUninit<T>],
+  initialized: usize,
+}
+
+impl<T> Drop for InitGuard<'_, T> {
+  fn drop(&mut self) {
+    let initialized = &mut self.data[..self.initialized];
+    // SAFETY: the first `initialized` elements were written to and not yet dropped
+    unsafe { ptr::drop_in_place(initialized as *mut [MaybeUninit<T>] as *mut [T]) }
+  }
+}
+
+/// Writes the first `data.len()` values to `data`, in order, stopping at the first error.
+/// If getting a value fails or panics, the values already written are dropped.
+/// Panics if there are fewer values than elements of `data`.
+pub fn try_write_all<T, E, I>(data: &mut [MaybeUninit<T>], values: I) -> Result<(), E>
+  where I: IntoIterator<Item = Result<T, E>>
+{
+  let len = data.len();
+  let mut guard = InitGuard { data, initialized: 0 };
+  let mut values = values.into_iter();
+  while guard.initialized < len {
+    let value = values.next().expect("Not enough values to initialize slice")?;
+    guard.data[guard.initialized].write(value);
+    guard.initialized += 1;
+  }
+  // Every element was initialized, so the caller is now responsible for dropping them
+  mem::forget(guard);
+  Ok(())
+}
+
 /// Insert a value at index `I` of `input`
 pub fn insert<const N: usize, const I: usize>(input: [usize; N], value: usize)
   -> [usize; N + 1]
@@ -35,3 +73,52 @@ pub fn remove<const N: usize, const I: usize>(input: [usize; N]) -> [usize; N -
   result[I..].copy_from_slice(&input[I + 1..]);
   result
 }
+
+/// Sums values using pairwise summation, whose rounding error grows with O(log(n))
+/// rather than O(n) as in naive summation.
+/// Blocks of values are summed naively, then the block sums are added in a balanced tree.
+/// The order of the additions depends only on the number of values.
+pub fn pairwise_sum<T, I>(values: I) -> T where
+  T: Add<Output = T> + Sum,
+  I: IntoIterator<Item = T>,
+{
+  const BLOCK_LEN: usize = 8;
+
+  // Sums of 2^level blocks each, with strictly decreasing levels
+  let mut partial_sums: Vec<(u32, T)> = vec![];
+  let mut values = values.into_iter().peekable();
+  while values.peek().is_some() {
+    let mut sum: T = values.by_ref().take(BLOCK_LEN).sum();
+    let mut level = 0;
+    // Merge sums of the same size, like incrementing a binary counter
+    while matches!(partial_sums.last(), Some(&(last_level, _)) if last_level == level) {
+      let (_, last_sum) = partial_sums.pop().unwrap();
+      sum = last_sum + sum;
+      level += 1;
+    }
+    partial_sums.push((level, sum));
+  }
+  // Add the remaining sums from smallest to largest
+  partial_sums.into_iter().rev()
+    .map(|(_, sum)| sum)
+    .reduce(|smaller_sum, sum| sum + smaller_sum)
+    .unwrap_or_else(|| iter::empty().sum())
+}
+
+/// Sums values using compensated (Kahan-Babuska-Neumaier) summation,
+/// which tracks the rounding error of each addition and adds it back in at the end.
+/// To first order, the rounding error is then independent of the number of values.
+pub fn compensated_sum<T: Float, I: IntoIterator<Item = T>>(values: I) -> T {
+  let mut sum = T::ZERO;
+  let mut compensation = T::ZERO;
+  for value in values {
+    let new_sum = sum + value;
+    // Recover the low-order bits lost by rounding, from whichever operand is smaller
+    let error =
+      if sum.abs() >= value.abs() { (sum - new_sum) + value }
+      else { (value - new_sum) + sum };
+    compensation = compensation + error;
+    sum = new_sum;
+  }
+  sum + compensation
+}
@@ -0,0 +1,52 @@
+use nd_slice::{NDBox, NDError, NDIntoIterator};
+
+mod util;
+use util::*;
+
+const HUGE: usize = 1 << 40;
+
+#[test]
+fn test_try_new_default() {
+  let values = NDBox::<i32, 2>::try_new_default([2, 3]).unwrap();
+  assert_eq!(values, NDBox::new_default([2, 3]));
+  let strings = NDBox::<String, 1>::try_new_default([2]).unwrap();
+  assert_eq!(strings, NDBox::from([String::new(), String::new()]));
+  // Empty boxes don't need to allocate
+  let empty = NDBox::<u8, 3>::try_new_default([4, 0, HUGE]).unwrap();
+  assert_eq!(empty.as_slice().len(), [4, 0, HUGE]);
+  // The product of the dimensions before the empty one can overflow
+  let empty = NDBox::<u8, 3>::try_new_default([HUGE, HUGE, 0]).unwrap();
+  assert_eq!(empty.as_slice().len(), [HUGE, HUGE, 0]);
+  let empty = NDBox::<u8, 3>::new_default([0, HUGE, HUGE]);
+  assert_eq!(empty.as_slice().len(), [0, HUGE, HUGE]);
+  assert_eq!(empty.as_slice().iter().count(), 0);
+}
+
+#[test]
+fn test_size_overflow() {
+  assert_eq!(
+    NDBox::<u8, 2>::try_new_default([HUGE, HUGE]),
+    Err(NDError::SizeOverflow { len: vec![HUGE, HUGE] }),
+  );
+  assert_panics_with(
+    || drop(NDBox::<u8, 2>::new_default([HUGE, HUGE])),
+    &format!("size of [{}, {}] overflows usize", HUGE, HUGE),
+  );
+  assert_panics_with(
+    || drop(NDBox::<u8, 3>::try_new_with([2, HUGE, HUGE], |_| Ok::<_, ()>(0))),
+    &format!("size of [2, {}, {}] overflows usize", HUGE, HUGE),
+  );
+}
+
+#[test]
+fn test_allocation_failure() {
+  // 2^60 elements fit in a usize, but their 2^63 bytes can never be allocated
+  assert_eq!(
+    NDBox::<u64, 2>::try_new_default([1 << 30, 1 << 30]),
+    Err(NDError::AllocationFailed { len: vec![1 << 30, 1 << 30] }),
+  );
+  assert_eq!(
+    NDError::AllocationFailed { len: vec![1 << 30, 1 << 30] }.to_string(),
+    "failed to allocate NDBox of [1073741824, 1073741824]",
+  );
+}
@@ -0,0 +1,165 @@
+use std::cell::Cell;
+use std::error::Error;
+use std::ops::Range;
+use nd_slice::{matrix_product, matrix_product_checked, Bounds, NDBox, NDError, NDIntoIterator};
+
+fn matrix() -> NDBox<i32, 2> {
+  NDBox::from([
+    [1, 2, 3],
+    [4, 5, 6],
+  ])
+}
+
+#[test]
+fn test_try_new_with() {
+  let result: Result<_, String> = NDBox::try_new_with([2, 3], |[index0, index1]| {
+    Ok(index0 * 3 + index1)
+  });
+  assert_eq!(result, Ok(NDBox::from([[0, 1, 2], [3, 4, 5]])));
+
+  let calls = Cell::new(0);
+  let values = NDBox::<_, 1>::from(["1", "2", "x", "4"]);
+  let result = NDBox::try_new_with([4], |index| {
+    calls.set(calls.get() + 1);
+    values[index].parse::<i32>()
+  });
+  // Stops at the first error
+  assert!(result.is_err());
+  assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn test_try_new_with_drops_initialized() {
+  let drops = Cell::new(0);
+  struct DropCounter<'a>(&'a Cell<usize>);
+
+  impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+      self.0.set(self.0.get() + 1);
+    }
+  }
+
+  let result = NDBox::try_new_with([3, 3], |[index0, index1]| {
+    if index0 == 1 && index1 == 2 { Err("failed") } else { Ok(DropCounter(&drops)) }
+  });
+  assert!(matches!(result, Err("failed")));
+  assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn test_try_index() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  assert_eq!(matrix.try_index([1, 2]), Ok(&6));
+  assert_eq!(
+    matrix.try_index([1, 3]),
+    Err(NDError::OutOfBounds { dimension: 1, index: 3, dimension_len: 3 }),
+  );
+  assert_eq!(
+    matrix.try_index([2, 5]),
+    Err(NDError::OutOfBounds { dimension: 0, index: 2, dimension_len: 2 }),
+  );
+
+  let mut matrix = self::matrix();
+  let mut matrix_mut = matrix.as_mut();
+  *matrix_mut.try_index_mut([0, 1]).unwrap() = 20;
+  assert!(matrix_mut.try_index_mut([0, 4]).is_err());
+  assert_eq!(matrix, NDBox::from([[1, 20, 3], [4, 5, 6]]));
+}
+
+#[test]
+fn test_try_extract() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  assert_eq!(matrix.try_extract::<1>(2), Ok(matrix.extract::<1>(2)));
+  assert_eq!(
+    matrix.try_extract::<0>(2),
+    Err(NDError::OutOfBounds { dimension: 0, index: 2, dimension_len: 2 }),
+  );
+
+  let mut matrix = self::matrix();
+  let mut matrix_mut = matrix.as_mut();
+  assert!(matrix_mut.try_extract_mut::<1>(3).is_err());
+  for (_, value) in matrix_mut.try_extract_mut::<0>(1).unwrap().iter_mut() {
+    *value = 0;
+  }
+  assert_eq!(matrix, NDBox::from([[1, 2, 3], [0, 0, 0]]));
+}
+
+#[test]
+fn test_try_slice() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  let bounds = [Bounds::all().from(1), Bounds::all().step(2)];
+  assert_eq!(matrix.try_slice(bounds), Ok(matrix.slice(bounds)));
+  assert_eq!(
+    matrix.try_slice([Bounds::all(), Bounds::all().from(1).to(4)]),
+    Err(NDError::RangeOutOfBounds { dimension: 1, range: 1..4, dimension_len: 3 }),
+  );
+  // Backwards ranges are also out of bounds
+  let range = Range { start: 2, end: 1 };
+  assert_eq!(
+    matrix.try_slice([Bounds::all().from(2).to(1), Bounds::all()]),
+    Err(NDError::RangeOutOfBounds { dimension: 0, range, dimension_len: 2 }),
+  );
+
+  let mut matrix = self::matrix();
+  let mut matrix_mut = matrix.as_mut();
+  assert!(matrix_mut.try_slice_mut([Bounds::all().from(3), Bounds::all()]).is_err());
+  let corner_bounds = [Bounds::all().from(1), Bounds::all().from(2)];
+  let mut corner = matrix_mut.try_slice_mut(corner_bounds).unwrap();
+  *corner.index_mut([0, 0]) = 60;
+  assert_eq!(matrix, NDBox::from([[1, 2, 3], [4, 5, 60]]));
+}
+
+#[test]
+fn test_try_zip() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  let sums: Vec<i32> = matrix.try_zip(matrix).unwrap().map(|(a, b)| a + b).collect();
+  assert_eq!(sums, [2, 4, 6, 8, 10, 12]);
+  assert_eq!(
+    matrix.try_zip(matrix.transpose()).err(),
+    Some(NDError::ShapeMismatch { len1: vec![2, 3], len2: vec![3, 2] }),
+  );
+}
+
+#[test]
+fn test_matrix_product_checked() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  assert_eq!(
+    matrix_product_checked::<_, _, i32>(matrix, matrix.transpose()),
+    Ok(matrix_product(matrix, matrix.transpose())),
+  );
+  assert_eq!(
+    matrix_product_checked::<_, _, i32>(matrix, matrix),
+    Err(NDError::ShapeMismatch { len1: vec![2, 3], len2: vec![2, 3] }),
+  );
+}
+
+#[test]
+fn test_error_messages() {
+  fn sum_column(matrix: &NDBox<i32, 2>, column: usize) -> Result<i32, Box<dyn Error>> {
+    Ok(matrix.as_slice().try_extract::<1>(column)?.into_iter().sum())
+  }
+
+  let matrix = matrix();
+  assert_eq!(sum_column(&matrix, 1).unwrap(), 7);
+  assert_eq!(
+    sum_column(&matrix, 3).unwrap_err().to_string(),
+    "index 3 out of bounds for dimension of len 3",
+  );
+  assert_eq!(
+    NDError::RangeOutOfBounds { dimension: 0, range: 2..5, dimension_len: 4 }.to_string(),
+    "range 2..5 out of bounds for dimension of len 4",
+  );
+  assert_eq!(
+    NDError::ShapeMismatch { len1: vec![2, 3], len2: vec![3] }.to_string(),
+    "incompatible lengths [2, 3] and [3]",
+  );
+  assert_eq!(
+    NDError::SizeOverflow { len: vec![usize::MAX, 2] }.to_string(),
+    format!("size of [{}, 2] overflows usize", usize::MAX),
+  );
+}
@@ -0,0 +1,152 @@
+use nd_slice::*;
+
+mod util;
+use util::*;
+
+fn signal() -> NDBox<i32, 1> {
+  NDBox::from([1, 2, 3, 4])
+}
+
+fn kernel() -> NDBox<i32, 1> {
+  NDBox::from([1, 10, 100])
+}
+
+#[test]
+fn test_convolve_1_dimension() {
+  let signal = signal();
+  let signal = signal.as_slice();
+  let kernel = kernel();
+  let kernel = kernel.as_slice();
+  assert_eq!(
+    convolve(signal, kernel, ConvolveMode::Full),
+    NDBox::from([1, 12, 123, 234, 340, 400]),
+  );
+  assert_eq!(convolve(signal, kernel, ConvolveMode::Same), NDBox::from([12, 123, 234, 340]));
+  assert_eq!(convolve(signal, kernel, ConvolveMode::Valid), NDBox::from([123, 234]));
+}
+
+#[test]
+fn test_correlate_1_dimension() {
+  let signal = signal();
+  let signal = signal.as_slice();
+  let kernel = kernel();
+  let kernel = kernel.as_slice();
+  assert_eq!(
+    correlate(signal, kernel, ConvolveMode::Full),
+    NDBox::from([100, 210, 321, 432, 43, 4]),
+  );
+  assert_eq!(correlate(signal, kernel, ConvolveMode::Same), NDBox::from([210, 321, 432, 43]));
+  assert_eq!(correlate(signal, kernel, ConvolveMode::Valid), NDBox::from([321, 432]));
+}
+
+#[test]
+fn test_same_even_kernel() {
+  let signal = signal();
+  let kernel = NDBox::from([1, 1]);
+  assert_eq!(
+    convolve(signal.as_slice(), kernel.as_slice(), ConvolveMode::Same),
+    NDBox::from([1, 3, 5, 7]),
+  );
+}
+
+#[test]
+fn test_valid_kernel_too_large() {
+  let signal = NDBox::from([1, 2]);
+  let kernel = kernel();
+  assert_eq!(
+    convolve(signal.as_slice(), kernel.as_slice(), ConvolveMode::Valid),
+    NDBox::new_default([0]),
+  );
+}
+
+#[test]
+fn test_empty_kernel() {
+  let signal = signal();
+  let kernel = NDBox::<i32, 1>::new_default([0]);
+  assert_panics_with(
+    || drop(convolve(signal.as_slice(), kernel.as_slice(), ConvolveMode::Full)),
+    "Cannot convolve with an empty kernel of Len([0])",
+  );
+}
+
+fn image() -> NDBox<i32, 2> {
+  NDBox::from([
+    [1, 2, 3, 4],
+    [5, 6, 7, 8],
+    [9, 10, 11, 12],
+  ])
+}
+
+#[test]
+fn test_convolve_2_dimensions() {
+  let image = image();
+  let image = image.as_slice();
+  let kernel = NDBox::from([
+    [1, 0],
+    [0, -1],
+  ]);
+  let kernel = kernel.as_slice();
+  assert_eq!(convolve(image, kernel, ConvolveMode::Valid), NDBox::new_fill([2, 3], 5));
+  assert_eq!(correlate(image, kernel, ConvolveMode::Valid), NDBox::new_fill([2, 3], -5));
+  assert_eq!(convolve(image, kernel, ConvolveMode::Full), NDBox::from([
+    [1, 2, 3, 4, 0],
+    [5, 5, 5, 5, -4],
+    [9, 5, 5, 5, -8],
+    [0, -9, -10, -11, -12],
+  ]));
+  assert_eq!(convolve(image, kernel, ConvolveMode::Same), NDBox::from([
+    [1, 2, 3, 4],
+    [5, 5, 5, 5],
+    [9, 5, 5, 5],
+  ]));
+}
+
+#[test]
+fn test_separable() {
+  let image = image();
+  let image = image.as_slice();
+  let kernel0 = NDBox::from([1, 2, 1]);
+  let kernel0 = kernel0.as_slice();
+  let kernel1 = NDBox::from([-1, 0, 3, 1]);
+  let kernel1 = kernel1.as_slice();
+  let kernel = outer(kernel0, kernel1);
+  let kernel = kernel.as_slice();
+  for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+    assert_eq!(
+      convolve_separable(image, [kernel0, kernel1], mode),
+      convolve(image, kernel, mode),
+    );
+    assert_eq!(
+      correlate_separable(image, [kernel0, kernel1], mode),
+      correlate(image, kernel, mode),
+    );
+  }
+}
+
+#[test]
+fn test_separable_3_dimensions() {
+  let volume = NDBox::new_with([3, 4, 5], |[i, j, k]| (i * 20 + j * 5 + k) as f64);
+  let volume = volume.as_slice();
+  let kernel0 = NDBox::from([0.25, 0.5, 0.25]);
+  let kernel1 = NDBox::from([1.0, -1.0]);
+  let kernel2 = NDBox::from([0.5, 0.5, 1.0]);
+  let kernels = [kernel0.as_slice(), kernel1.as_slice(), kernel2.as_slice()];
+  let kernel = NDBox::new_with([3, 2, 3], |[i, j, k]| {
+    kernel0[[i]] * kernel1[[j]] * kernel2[[k]]
+  });
+  let kernel = kernel.as_slice();
+  for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+    let expected = convolve(volume, kernel, mode);
+    let actual = convolve_separable(volume, kernels, mode);
+    assert_eq!(actual.as_slice().len(), expected.as_slice().len());
+    for (index, value) in actual.as_slice().iter() {
+      assert!((value - expected[index]).abs() < 1e-9);
+    }
+  }
+}
+
+#[test]
+fn test_separable_0_dimensions() {
+  let value = NDBox::from(123);
+  assert_eq!(convolve_separable(value.as_slice(), [], ConvolveMode::Full), NDBox::from(123));
+}
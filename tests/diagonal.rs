@@ -0,0 +1,121 @@
+use nd_slice::{tril, triu, Bounds, NDBox};
+
+fn array() -> NDBox<i32, 2> {
+  NDBox::from([
+    [1, 2, 3, 4],
+    [5, 6, 7, 8],
+    [9, 10, 11, 12],
+  ])
+}
+
+#[test]
+fn test_diagonal() {
+  let array = array();
+  let array = array.as_slice();
+  assert_eq!(array.diagonal(0), NDBox::from([1, 6, 11]).as_slice());
+  assert_eq!(array.diagonal(1), NDBox::from([2, 7, 12]).as_slice());
+  assert_eq!(array.diagonal(2), NDBox::from([3, 8]).as_slice());
+  assert_eq!(array.diagonal(3), NDBox::from([4]).as_slice());
+  assert_eq!(array.diagonal(-1), NDBox::from([5, 10]).as_slice());
+  assert_eq!(array.diagonal(-2), NDBox::from([9]).as_slice());
+}
+
+#[test]
+fn test_diagonal_out_of_range() {
+  let array = array();
+  let array = array.as_slice();
+  assert_eq!(array.diagonal(4), NDBox::<i32, 1>::new_default([0]).as_slice());
+  assert_eq!(array.diagonal(10), NDBox::<i32, 1>::new_default([0]).as_slice());
+  assert_eq!(array.diagonal(-3), NDBox::<i32, 1>::new_default([0]).as_slice());
+  assert_eq!(array.diagonal(isize::MIN), NDBox::<i32, 1>::new_default([0]).as_slice());
+}
+
+#[test]
+fn test_diagonal_transpose_and_slice() {
+  let array = array();
+  let array = array.as_slice();
+  assert_eq!(array.transpose().diagonal(1), NDBox::from([5, 10]).as_slice());
+  assert_eq!(
+    array.slice([Bounds::all(), Bounds::all().step(2)]).diagonal(0),
+    NDBox::from([1, 7]).as_slice(),
+  );
+}
+
+#[test]
+fn test_diagonal_mut() {
+  let mut array = array();
+  let mut array = array.as_mut();
+  for value in &mut array.diagonal_mut(-1) {
+    *value = 0;
+  }
+  array.diagonal_mut(1)[[2]] = -1;
+  assert_eq!(array, NDBox::from([
+    [1, 2, 3, 4],
+    [0, 6, 7, 8],
+    [9, 0, 11, -1],
+  ]).as_slice());
+}
+
+#[test]
+fn test_eye() {
+  assert_eq!(NDBox::<i32, 2>::eye(3), NDBox::from([
+    [1, 0, 0],
+    [0, 1, 0],
+    [0, 0, 1],
+  ]));
+  assert_eq!(NDBox::<f64, 2>::eye(0), NDBox::new_default([0, 0]));
+}
+
+#[test]
+fn test_from_diagonal() {
+  let diagonal = NDBox::from([1, 2, 3]);
+  let matrix = NDBox::from_diagonal(diagonal.as_slice());
+  assert_eq!(matrix, NDBox::from([
+    [1, 0, 0],
+    [0, 2, 0],
+    [0, 0, 3],
+  ]));
+  assert_eq!(matrix.as_slice().diagonal(0), diagonal.as_slice());
+}
+
+#[test]
+fn test_tril() {
+  let array = array();
+  let array = array.as_slice();
+  assert_eq!(tril(array, 0), NDBox::from([
+    [1, 0, 0, 0],
+    [5, 6, 0, 0],
+    [9, 10, 11, 0],
+  ]));
+  assert_eq!(tril(array, 1), NDBox::from([
+    [1, 2, 0, 0],
+    [5, 6, 7, 0],
+    [9, 10, 11, 12],
+  ]));
+  assert_eq!(tril(array, -1), NDBox::from([
+    [0, 0, 0, 0],
+    [5, 0, 0, 0],
+    [9, 10, 0, 0],
+  ]));
+}
+
+#[test]
+fn test_triu() {
+  let array = array();
+  let array = array.as_slice();
+  assert_eq!(triu(array, 0), NDBox::from([
+    [1, 2, 3, 4],
+    [0, 6, 7, 8],
+    [0, 0, 11, 12],
+  ]));
+  assert_eq!(triu(array, 2), NDBox::from([
+    [0, 0, 3, 4],
+    [0, 0, 0, 8],
+    [0, 0, 0, 0],
+  ]));
+  assert_eq!(triu(array, -1), NDBox::from([
+    [1, 2, 3, 4],
+    [5, 6, 7, 8],
+    [0, 10, 11, 12],
+  ]));
+}
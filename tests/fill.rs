@@ -0,0 +1,142 @@
+use nd_slice::{Bounds, NDBox};
+
+mod util;
+use util::*;
+
+fn matrix() -> NDBox<i32, 2> {
+  NDBox::from([
+    [1, 2, 3, 4],
+    [5, 6, 7, 8],
+    [9, 10, 11, 12],
+  ])
+}
+
+#[test]
+fn test_new_uninit() {
+  let mut values = NDBox::<String, 2>::new_uninit([2, 3]);
+  let mut values_mut = values.as_mut();
+  // Initialize the elements in reverse order
+  for index in (0..6).rev() {
+    let index = [index / 3, index % 3];
+    values_mut.index_mut(index).write(format!("{:?}", index));
+  }
+  // SAFETY: every element was initialized
+  let values = unsafe { values.assume_init() };
+  assert_eq!(values, NDBox::new_with([2, 3], |index| format!("{:?}", index)));
+
+  let empty = NDBox::<String, 3>::new_uninit([2, 0, 3]);
+  // SAFETY: there are no elements to initialize
+  let empty = unsafe { empty.assume_init() };
+  assert!(empty == NDBox::<String, 3>::new_default([2, 0, 3]));
+}
+
+#[test]
+fn test_fill() {
+  let mut matrix = matrix();
+  matrix.as_mut().slice_mut([Bounds::all().step(2), Bounds::all().from(1).to(3)]).fill(0);
+  assert_eq!(matrix, NDBox::from([
+    [1, 0, 0, 4],
+    [5, 6, 7, 8],
+    [9, 0, 0, 12],
+  ]));
+
+  let mut next = 100;
+  matrix.as_mut().extract_mut::<1>(3).fill_with(|| {
+    next += 1;
+    next
+  });
+  assert_eq!(matrix, NDBox::from([
+    [1, 0, 0, 101],
+    [5, 6, 7, 102],
+    [9, 0, 0, 103],
+  ]));
+}
+
+#[test]
+fn test_assign() {
+  let mut matrix = matrix();
+  let column = NDBox::<_, 2>::from([[-1], [-2], [-3]]);
+  matrix.as_mut().slice_mut([Bounds::all(), Bounds::all().from(2).to(3)]).assign(column.as_slice());
+  assert_eq!(matrix, NDBox::from([
+    [1, 2, -1, 4],
+    [5, 6, -2, 8],
+    [9, 10, -3, 12],
+  ]));
+
+  // Assigning from a box moves its elements
+  let mut strings = NDBox::<String, 1>::new_default([2]);
+  strings.as_mut().assign(NDBox::from([String::from("a"), String::from("b")]));
+  assert_eq!(strings, NDBox::from([String::from("a"), String::from("b")]));
+
+  let mut matrix = self::matrix();
+  assert_panics_with(
+    move || matrix.as_mut().assign(column.as_slice()),
+    "Cannot operate on NDSlices with Len([3, 4]) and Len([3, 1])",
+  );
+}
+
+#[test]
+fn test_copy_from() {
+  let source = matrix();
+  let source = source.as_slice();
+
+  // Entirely contiguous
+  let mut destination = NDBox::new_default([3, 4]);
+  destination.as_mut().copy_from(source);
+  assert_eq!(destination.as_slice(), source);
+
+  // Contiguous rows
+  let mut destination = NDBox::new_default([3, 6]);
+  let middle = [Bounds::all(), Bounds::all().from(1).to(5)];
+  destination.as_mut().slice_mut(middle).copy_from(source);
+  assert_eq!(destination, NDBox::from([
+    [0, 1, 2, 3, 4, 0],
+    [0, 5, 6, 7, 8, 0],
+    [0, 9, 10, 11, 12, 0],
+  ]));
+
+  // Strided
+  let mut destination = NDBox::new_default([4, 3]);
+  destination.as_mut().copy_from(source.transpose());
+  assert_eq!(destination.as_slice(), source.transpose());
+  let mut destination = NDBox::new_default([3, 2]);
+  destination.as_mut().copy_from(source.slice([Bounds::all(), Bounds::all().step(2)]));
+  assert_eq!(destination, NDBox::from([[1, 3], [5, 7], [9, 11]]));
+
+  // Repeated without copying
+  let row = NDBox::<_, 2>::from([[1, 2, 3, 4]]);
+  let mut destination = NDBox::new_default([3, 4]);
+  destination.as_mut().copy_from(row.as_slice().repeat_view::<0>(3));
+  assert_eq!(destination, NDBox::new_with([3, 4], |[_, index1]| index1 as i32 + 1));
+
+  let mut scalar = NDBox::from(0);
+  scalar.as_mut().copy_from(NDBox::from(5).as_slice());
+  assert_eq!(scalar, NDBox::from(5));
+  let mut empty = NDBox::<i32, 2>::new_default([0, 4]);
+  empty.as_mut().copy_from(source.slice([Bounds::all().to(0), Bounds::all()]));
+
+  assert_panics_with(
+    || NDBox::new_default([4, 3]).as_mut().copy_from(source),
+    "Cannot operate on NDSlices with Len([4, 3]) and Len([3, 4])",
+  );
+}
+
+#[test]
+fn test_swap_with() {
+  let mut matrix1 = matrix();
+  let mut matrix2 = NDBox::new_with([3, 4], |_| 0);
+  matrix1.as_mut().swap_with(&mut matrix2.as_mut());
+  assert_eq!(matrix1, NDBox::new_with([3, 4], |_| 0));
+  assert_eq!(matrix2, matrix());
+
+  // Swap the first column with a column of another box
+  let mut column = NDBox::<_, 2>::from([[0], [-1], [-2]]);
+  let first = [Bounds::all(), Bounds::all().to(1)];
+  matrix2.as_mut().slice_mut(first).swap_with(&mut column.as_mut());
+  assert_eq!(matrix2, NDBox::from([
+    [0, 2, 3, 4],
+    [-1, 6, 7, 8],
+    [-2, 10, 11, 12],
+  ]));
+  assert_eq!(column, NDBox::from([[1], [5], [9]]));
+}
@@ -0,0 +1,117 @@
+use nd_slice::{NDBox, NDIntoIterator};
+
+mod util;
+use util::*;
+
+fn temperatures() -> NDBox<i32, 2> {
+  // Days x cities
+  NDBox::from([
+    [72, 80, 79],
+    [79, 79, 79],
+    [76, 73, 83],
+    [80, 70, 72],
+  ])
+}
+
+#[test]
+fn test_select() {
+  let temperatures = temperatures();
+  let temperatures = temperatures.as_slice();
+  assert_eq!(temperatures.select::<1>(&[2, 0]), NDBox::from([
+    [79, 72],
+    [79, 79],
+    [83, 76],
+    [72, 80],
+  ]));
+  assert_eq!(temperatures.select::<0>(&[3, 3, 1]), NDBox::from([
+    [80, 70, 72],
+    [80, 70, 72],
+    [79, 79, 79],
+  ]));
+  assert_eq!(temperatures.select::<0>(&[]), NDBox::new_default([0, 3]));
+}
+
+#[test]
+fn test_select_out_of_bounds() {
+  let temperatures = temperatures();
+  assert_panics_with(
+    || drop(temperatures.as_slice().select::<1>(&[0, 3])),
+    "index 3 out of bounds for dimension of len 3",
+  );
+}
+
+#[test]
+fn test_take_along_axis() {
+  let temperatures = temperatures();
+  let temperatures = temperatures.as_slice();
+  // The hottest day in each city
+  let hottest_days = NDBox::from([[3, 0, 2]]);
+  assert_eq!(
+    temperatures.take_along_axis::<0>(hottest_days.as_slice()),
+    NDBox::from([[80, 80, 83]]),
+  );
+  let indices = NDBox::from([
+    [0, 0],
+    [2, 1],
+    [1, 1],
+    [0, 2],
+  ]);
+  assert_eq!(temperatures.take_along_axis::<1>(indices.as_slice()), NDBox::from([
+    [72, 72],
+    [79, 79],
+    [73, 73],
+    [80, 72],
+  ]));
+}
+
+#[test]
+fn test_take_along_axis_invalid() {
+  let temperatures = temperatures();
+  let temperatures = temperatures.as_slice();
+  let indices = NDBox::from([[0, 0]]);
+  assert_panics_with(
+    || drop(temperatures.take_along_axis::<0>(indices.as_slice())),
+    "Cannot index Len([4, 3]) along dimension 0 with indices of Len([1, 2])",
+  );
+  let indices = NDBox::from([[0, 4, 0]]);
+  assert_panics_with(
+    || drop(temperatures.take_along_axis::<0>(indices.as_slice())),
+    "index 4 out of bounds for dimension of len 4",
+  );
+}
+
+#[test]
+fn test_put_along_axis() {
+  let mut temperatures = temperatures();
+  let indices = NDBox::from([[3, 0, 2]]);
+  temperatures.as_mut().put_along_axis::<0, _>(indices.as_slice(), NDBox::from([[0, 1, 2]]));
+  assert_eq!(temperatures, NDBox::from([
+    [72, 1, 79],
+    [79, 79, 79],
+    [76, 73, 2],
+    [0, 70, 72],
+  ]));
+}
+
+#[test]
+fn test_scatter_add() {
+  // Count the days in each 5-degree bin for each city
+  let temperatures = temperatures();
+  let bins = temperatures.as_slice().map(|&temperature| (temperature as usize - 70) / 5);
+  let mut counts = NDBox::new_fill([3, 3], 0);
+  counts.as_mut().scatter_add::<0, _>(bins.as_slice(), NDBox::new_fill([4, 3], 1));
+  assert_eq!(counts, NDBox::from([
+    [1, 2, 1],
+    [2, 1, 2],
+    [1, 1, 1],
+  ]));
+}
+
+#[test]
+fn test_scatter_add_ref() {
+  let values = NDBox::from([[1.5, 2.5, 3.5, 4.5]]);
+  let indices = NDBox::from([[1, 0, 1, 1]]);
+  let mut sums = NDBox::new_fill([1, 2], 0.0);
+  sums.as_mut().scatter_add::<1, _>(indices.as_slice(), values.as_slice());
+  assert_eq!(sums, NDBox::from([[2.5, 9.5]]));
+}
@@ -0,0 +1,231 @@
+use nd_slice::{Bounds, Layout, NDBox, NDCow, NDIntoIterator};
+
+fn matrix() -> NDBox<i32, 2> {
+  NDBox::from([
+    [1, 2, 3, 4],
+    [5, 6, 7, 8],
+    [9, 10, 11, 12],
+  ])
+}
+
+#[test]
+fn test_is_standard_layout() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  assert!(matrix.is_standard_layout());
+  assert!(matrix.slice([Bounds::all().from(1), Bounds::all()]).is_standard_layout());
+  // Selecting a single row or column leaves a dimension of length 1
+  assert!(matrix.slice([Bounds::all().from(1).to(2), Bounds::all().to(3)]).is_standard_layout());
+  assert!(!matrix.slice([Bounds::all(), Bounds::all().to(3)]).is_standard_layout());
+  assert!(!matrix.slice([Bounds::all(), Bounds::all().step(2)]).is_standard_layout());
+  assert!(!matrix.transpose().is_standard_layout());
+  assert!(matrix.slice([Bounds::all().from(1).to(1), Bounds::all().step(2)]).is_standard_layout());
+}
+
+#[test]
+fn test_as_contiguous() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  assert_eq!(
+    matrix.slice([Bounds::all().from(1), Bounds::all()]).as_contiguous(),
+    Some(&[5, 6, 7, 8, 9, 10, 11, 12][..]),
+  );
+  assert_eq!(matrix.transpose().as_contiguous(), None);
+}
+
+#[test]
+fn test_to_owned() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  assert_eq!(matrix.to_owned().as_slice(), matrix);
+  // Contiguous rows
+  let columns = matrix.slice([Bounds::all(), Bounds::all().from(1).to(3)]);
+  assert_eq!(columns.to_owned(), NDBox::from([[2, 3], [6, 7], [10, 11]]));
+  // Strided rows
+  let transpose = matrix.transpose();
+  assert_eq!(
+    transpose.to_owned(),
+    NDBox::from([[1, 5, 9], [2, 6, 10], [3, 7, 11], [4, 8, 12]]),
+  );
+  assert_eq!(transpose.to_owned().as_slice().as_contiguous().map(<[i32]>::len), Some(12));
+  let stepped = matrix.slice([Bounds::all().step(2), Bounds::all().step(3)]);
+  assert_eq!(stepped.to_owned(), NDBox::from([[1, 4], [9, 12]]));
+  let empty = matrix.slice([Bounds::all(), Bounds::all().to(0)]);
+  assert_eq!(empty.to_owned().as_slice().len(), [3, 0]);
+}
+
+#[test]
+fn test_to_owned_clone() {
+  let strings = NDBox::new_with([2, 3], |[i, j]| format!("{}{}", i, j));
+  let transpose = strings.as_slice().transpose().to_owned();
+  assert_eq!(transpose, NDBox::from([
+    ["00".to_string(), "10".to_string()],
+    ["01".to_string(), "11".to_string()],
+    ["02".to_string(), "12".to_string()],
+  ]));
+}
+
+#[test]
+fn test_to_owned_in() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  let row_major = matrix.to_owned_in(Layout::RowMajor);
+  assert_eq!(row_major.as_slice(), matrix);
+  let column_major = matrix.to_owned_in(Layout::ColumnMajor);
+  assert_eq!(column_major.as_slice(), matrix);
+  assert_eq!(column_major[[1, 2]], 7);
+  assert!(!column_major.as_slice().is_standard_layout());
+  // The transpose of a column-major box is contiguous in row-major order
+  assert_eq!(
+    column_major.as_slice().transpose().as_contiguous(),
+    Some(&[1, 5, 9, 2, 6, 10, 3, 7, 11, 4, 8, 12][..]),
+  );
+  // Owned iteration is still in row-major order
+  let values: Vec<_> = column_major.into_iter().collect();
+  assert_eq!(values, (1..=12).collect::<Vec<_>>());
+
+  let strings = NDBox::new_with([2, 2], |[i, j]| format!("{}{}", i, j));
+  let strings = strings.as_slice().to_owned_in(Layout::ColumnMajor);
+  // Iteration with indices is in memory order
+  let values: Vec<_> = strings.iter_owned().collect();
+  assert_eq!(values, vec![
+    ([0, 0], "00".to_string()),
+    ([1, 0], "10".to_string()),
+    ([0, 1], "01".to_string()),
+    ([1, 1], "11".to_string()),
+  ]);
+}
+
+#[test]
+fn test_as_standard_layout() {
+  let matrix = matrix();
+  let matrix = matrix.as_slice();
+  let borrowed = matrix.as_standard_layout();
+  assert!(!borrowed.is_owned());
+  assert!(matches!(borrowed, NDCow::Borrowed(_)));
+  assert_eq!(borrowed.as_slice(), matrix);
+  assert_eq!(borrowed.into_owned().as_slice(), matrix);
+
+  let transpose = matrix.transpose();
+  let owned = transpose.as_standard_layout();
+  assert!(owned.is_owned());
+  assert!(owned.as_slice().is_standard_layout());
+  assert_eq!(owned.as_slice(), transpose);
+  assert_eq!(owned.into_owned().as_slice(), transpose);
+}
+
+#[test]
+fn test_new_with_in() {
+  let mut calls = vec![];
+  let column_major = NDBox::new_with_in([2, 3], Layout::ColumnMajor, |[i, j]| {
+    calls.push([i, j]);
+    i * 3 + j
+  });
+  // The initializer is called in memory order
+  assert_eq!(calls, vec![[0, 0], [1, 0], [0, 1], [1, 1], [0, 2], [1, 2]]);
+  assert_eq!(column_major.layout(), Layout::ColumnMajor);
+  assert_eq!(column_major, NDBox::from([[0, 1, 2], [3, 4, 5]]));
+  assert_eq!(column_major.as_raw_slice(), &[0, 3, 1, 4, 2, 5]);
+
+  let row_major = NDBox::new_with_in([2, 3], Layout::RowMajor, |[i, j]| i * 3 + j);
+  assert_eq!(row_major.layout(), Layout::RowMajor);
+  assert_eq!(row_major.as_raw_slice(), &[0, 1, 2, 3, 4, 5]);
+  assert_eq!(NDBox::new_with([2], |[i]| i).layout(), Layout::RowMajor);
+
+  let result = NDBox::try_new_with_in([2, 2], Layout::ColumnMajor, |[i, j]| {
+    if [i, j] == [0, 1] { Err(j) } else { Ok(i) }
+  });
+  assert_eq!(result.err(), Some(1));
+}
+
+#[test]
+fn test_new_fill_in() {
+  let filled = NDBox::new_fill_in([2, 3, 4], Layout::ColumnMajor, 7);
+  assert!(filled == NDBox::new_fill([2, 3, 4], 7));
+  let default = NDBox::<i32, 2>::new_default_in([3, 2], Layout::ColumnMajor);
+  assert_eq!(default.layout(), Layout::ColumnMajor);
+  assert_eq!(default, NDBox::new_default([3, 2]));
+
+  let mut uninit = NDBox::<u8, 2>::new_uninit_in([2, 2], Layout::ColumnMajor);
+  for (index, value) in uninit.as_raw_slice_mut().iter_mut().enumerate() {
+    value.write(index as u8);
+  }
+  let values = unsafe { uninit.assume_init() };
+  assert_eq!(values.layout(), Layout::ColumnMajor);
+  assert_eq!(values, NDBox::from([[0, 2], [1, 3]]));
+}
+
+#[test]
+fn test_column_major_3d() {
+  let values = NDBox::new_with_in([2, 3, 4], Layout::ColumnMajor, |[i, j, k]| (i, j, k));
+  let expected = NDBox::new_with([2, 3, 4], |[i, j, k]| (i, j, k));
+  assert!(values == expected);
+  assert_eq!(values.as_raw_slice()[..3], [(0, 0, 0), (1, 0, 0), (0, 1, 0)]);
+  assert_eq!(values.as_raw_slice()[6], (0, 0, 1));
+  assert_eq!(values.as_slice().extract::<2>(1), expected.as_slice().extract::<2>(1));
+  let mut indices: Vec<_> = values.iter_owned().collect();
+  assert_eq!(indices[..3], [
+    ([0, 0, 0], (0, 0, 0)),
+    ([1, 0, 0], (1, 0, 0)),
+    ([0, 1, 0], (0, 1, 0)),
+  ]);
+  assert!(indices.iter().all(|&([i, j, k], value)| value == (i, j, k)));
+  indices.sort();
+  let expected_indices: Vec<_> = expected.iter_owned().collect();
+  assert_eq!(indices, expected_indices);
+}
+
+#[test]
+fn test_raw_vec() {
+  // A 2x3 matrix from Fortran: columns are contiguous
+  let fortran = vec![1, 4, 2, 5, 3, 6];
+  let mut matrix = NDBox::from_raw_vec([2, 3], Layout::ColumnMajor, fortran.clone());
+  assert_eq!(matrix, NDBox::from([[1, 2, 3], [4, 5, 6]]));
+  matrix[[1, 0]] = 40;
+  assert_eq!(matrix.as_raw_slice()[1], 40);
+  matrix.as_raw_slice_mut()[1] = 4;
+  assert_eq!(matrix.into_raw_vec(), fortran);
+
+  let row_major = NDBox::from_raw_vec([3, 2], Layout::RowMajor, vec![1, 2, 3, 4, 5, 6]);
+  assert_eq!(row_major, NDBox::from([[1, 2], [3, 4], [5, 6]]));
+}
+
+#[test]
+#[should_panic(expected = "Cannot create NDBox of Len([2, 3]) from 5 elements")]
+fn test_raw_vec_wrong_len() {
+  NDBox::from_raw_vec([2, 3], Layout::ColumnMajor, vec![0; 5]);
+}
+
+#[test]
+fn test_layout_conversions() {
+  let matrix = matrix();
+  let column_major = matrix.to_layout(Layout::ColumnMajor);
+  assert_eq!(column_major.layout(), Layout::ColumnMajor);
+  assert_eq!(column_major, matrix);
+  assert_eq!(column_major.as_raw_slice(), &[1, 5, 9, 2, 6, 10, 3, 7, 11, 4, 8, 12]);
+  // Cloning preserves the layout
+  assert_eq!(column_major.clone().as_raw_slice(), column_major.as_raw_slice());
+  let row_major = column_major.to_layout(Layout::RowMajor);
+  assert_eq!(row_major.as_raw_slice(), matrix.as_raw_slice());
+
+  let strings = NDBox::new_with([2, 3], |[i, j]| format!("{}{}", i, j));
+  let column_major = strings.clone().into_layout(Layout::ColumnMajor);
+  assert_eq!(column_major.layout(), Layout::ColumnMajor);
+  assert_eq!(column_major, strings);
+  assert_eq!(column_major.as_raw_slice()[..2], ["00".to_string(), "10".to_string()]);
+  let column_major = column_major.into_layout(Layout::ColumnMajor);
+  let row_major = column_major.into_layout(Layout::RowMajor);
+  assert_eq!(row_major.into_raw_vec(), strings.into_raw_vec());
+}
+
+#[test]
+fn test_column_major_operations() {
+  let matrix = matrix();
+  let mut column_major = matrix.to_layout(Layout::ColumnMajor);
+  column_major.as_mut().slice_mut([Bounds::all().to(1), Bounds::all()]).fill(0);
+  assert_eq!(column_major.as_raw_slice()[..4], [0, 5, 9, 0]);
+  let doubled = column_major.as_slice().map(|value| value * 2);
+  assert_eq!(doubled, NDBox::from([[0, 0, 0, 0], [10, 12, 14, 16], [18, 20, 22, 24]]));
+  let row_major = matrix.to_layout(Layout::RowMajor);
+  assert_eq!(matrix.to_layout(Layout::ColumnMajor) + row_major, matrix.as_slice().map(|v| v * 2));
+}
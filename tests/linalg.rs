@@ -0,0 +1,235 @@
+use nd_slice::{dot, expm, kron, matrix_power, matvec, outer, NDBox, NDIntoIterator};
+
+mod util;
+use util::*;
+
+fn assert_close(actual: NDBox<f64, 2>, expected: NDBox<f64, 2>) {
+  assert_eq!(actual.as_slice().len(), expected.as_slice().len());
+  for (index, value) in actual.as_slice().iter() {
+    let expected_value = expected[index];
+    assert!(
+      (value - expected_value).abs() < 1e-9,
+      "{:?} != {:?} at {:?}", actual, expected, index,
+    );
+  }
+}
+
+#[test]
+fn test_matrix_power() {
+  let fibonacci = NDBox::from([
+    [1, 1],
+    [1, 0],
+  ]);
+  let fibonacci = fibonacci.as_slice();
+  assert_eq!(matrix_power(fibonacci, 1), NDBox::from([
+    [1, 1],
+    [1, 0],
+  ]));
+  assert_eq!(matrix_power(fibonacci, 2), NDBox::from([
+    [2, 1],
+    [1, 1],
+  ]));
+  assert_eq!(matrix_power(fibonacci, 10), NDBox::from([
+    [89, 55],
+    [55, 34],
+  ]));
+  assert_eq!(matrix_power(fibonacci, 45), NDBox::from([
+    [1836311903, 1134903170],
+    [1134903170, 701408733],
+  ]));
+}
+
+#[test]
+fn test_matrix_power_0() {
+  let matrix = NDBox::from([
+    [1, 2, 3],
+    [4, 5, 6],
+    [7, 8, 9],
+  ]);
+  assert_eq!(matrix_power(matrix.as_slice(), 0), NDBox::from([
+    [1, 0, 0],
+    [0, 1, 0],
+    [0, 0, 1],
+  ]));
+}
+
+#[test]
+fn test_matrix_power_transpose() {
+  let matrix = NDBox::from([
+    [1, 2],
+    [3, 4],
+  ]);
+  assert_eq!(matrix_power(matrix.as_slice().transpose(), 3), NDBox::from([
+    [37, 81],
+    [54, 118],
+  ]));
+}
+
+#[test]
+fn test_matrix_power_not_square() {
+  let matrix = NDBox::from([
+    [1, 2, 3],
+    [4, 5, 6],
+  ]);
+  assert_panics_with(
+    || drop(matrix_power(matrix.as_slice(), 2)),
+    "Cannot raise to a power non-square matrix of Len([2, 3])",
+  );
+}
+
+#[test]
+fn test_expm_zero() {
+  let zero = NDBox::new_fill([3, 3], 0.0);
+  assert_close(expm(zero.as_slice()), NDBox::from([
+    [1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, 0.0, 1.0],
+  ]));
+}
+
+#[test]
+fn test_expm_diagonal() {
+  let diagonal = NDBox::from([
+    [1.0, 0.0, 0.0],
+    [0.0, -2.0, 0.0],
+    [0.0, 0.0, 10.0],
+  ]);
+  let expected = NDBox::from([
+    [1f64.exp(), 0.0, 0.0],
+    [0.0, (-2f64).exp(), 0.0],
+    [0.0, 0.0, 10f64.exp()],
+  ]);
+  let actual = expm(diagonal.as_slice());
+  for (index, &value) in actual.as_slice().iter() {
+    assert!((value - expected[index]).abs() <= 1e-12 * expected[index].abs().max(1.0));
+  }
+}
+
+#[test]
+fn test_expm_nilpotent() {
+  let matrix = NDBox::from([
+    [0.0, 1.0, 0.0],
+    [0.0, 0.0, 1.0],
+    [0.0, 0.0, 0.0],
+  ]);
+  assert_close(expm(matrix.as_slice()), NDBox::from([
+    [1.0, 1.0, 0.5],
+    [0.0, 1.0, 1.0],
+    [0.0, 0.0, 1.0],
+  ]));
+}
+
+#[test]
+fn test_expm_rotation() {
+  let angle = 2.5;
+  let matrix = NDBox::from([
+    [0.0, -angle],
+    [angle, 0.0],
+  ]);
+  assert_close(expm(matrix.as_slice()), NDBox::from([
+    [angle.cos(), -angle.sin()],
+    [angle.sin(), angle.cos()],
+  ]));
+}
+
+#[test]
+fn test_expm_f32() {
+  let matrix = NDBox::from([
+    [1f32, 2.0],
+    [0.0, 1.0],
+  ]);
+  let result = expm(matrix.as_slice());
+  let e = 1f32.exp();
+  for (index, expected) in [([0, 0], e), ([0, 1], 2.0 * e), ([1, 0], 0.0), ([1, 1], e)] {
+    assert!((result[index] - expected).abs() < 1e-5);
+  }
+}
+
+#[test]
+fn test_expm_not_square() {
+  let matrix = NDBox::new_fill([2, 3], 1.0);
+  assert_panics_with(
+    || drop(expm(matrix.as_slice())),
+    "Cannot exponentiate non-square matrix of Len([2, 3])",
+  );
+}
+
+#[test]
+fn test_dot() {
+  let vector1 = NDBox::from([1, 2, 3]);
+  let vector2 = NDBox::from([4, -5, 6]);
+  assert_eq!(dot::<_, _, i32>(vector1.as_slice(), vector2.as_slice()), 4 - 10 + 18);
+  let empty = NDBox::<i32, 1>::new_default([0]);
+  assert_eq!(dot::<_, _, i32>(empty.as_slice(), empty.as_slice()), 0);
+}
+
+#[test]
+fn test_dot_invalid_lengths() {
+  let vector1 = NDBox::from([1, 2, 3]);
+  let vector2 = NDBox::from([4, 5]);
+  assert_panics_with(
+    || drop(dot::<_, _, i32>(vector1.as_slice(), vector2.as_slice())),
+    "Cannot multiply vectors of Len([3]) and Len([2])",
+  );
+}
+
+#[test]
+fn test_matvec() {
+  let matrix = NDBox::from([
+    [1, 2, 3],
+    [4, 5, 6],
+  ]);
+  let matrix = matrix.as_slice();
+  let vector = NDBox::from([1, 0, -1]);
+  assert_eq!(matvec::<_, _, i32>(matrix, vector.as_slice()), NDBox::from([-2, -2]));
+  let vector = NDBox::from([2, 3]);
+  assert_eq!(matvec::<_, _, i32>(matrix.transpose(), vector.as_slice()), NDBox::from([14, 19, 24]));
+}
+
+#[test]
+fn test_matvec_invalid_lengths() {
+  let matrix = NDBox::new_fill([2, 3], 1);
+  let vector = NDBox::from([1, 2]);
+  assert_panics_with(
+    || drop(matvec::<_, _, i32>(matrix.as_slice(), vector.as_slice())),
+    "Cannot multiply matrix of Len([2, 3]) by vector of Len([2])",
+  );
+}
+
+#[test]
+fn test_outer() {
+  let vector1 = NDBox::from([1, 2, 3]);
+  let vector2 = NDBox::from([10, 20]);
+  assert_eq!(outer(vector1.as_slice(), vector2.as_slice()), NDBox::from([
+    [10, 20],
+    [20, 40],
+    [30, 60],
+  ]));
+}
+
+#[test]
+fn test_kron() {
+  let matrix1 = NDBox::<_, 2>::from([
+    [1, 2],
+    [3, 4],
+  ]);
+  let matrix2 = NDBox::<_, 2>::from([
+    [0, 5, 1],
+    [6, 7, 0],
+  ]);
+  assert_eq!(kron(matrix1.as_slice(), matrix2.as_slice()), NDBox::from([
+    [0, 5, 1, 0, 10, 2],
+    [6, 7, 0, 12, 14, 0],
+    [0, 15, 3, 0, 20, 4],
+    [18, 21, 0, 24, 28, 0],
+  ]));
+}
+
+#[test]
+fn test_kron_3_dimensions() {
+  let slice1 = NDBox::new_with([2, 1, 2], |[i, _, k]| (i * 2 + k) as i32);
+  let slice2 = NDBox::new_fill([1, 2, 1], 10);
+  let product = kron(slice1.as_slice(), slice2.as_slice());
+  assert_eq!(product.as_slice().len(), [2, 2, 2]);
+  assert!(product == NDBox::new_with([2, 2, 2], |[i, _, k]| (i * 2 + k) as i32 * 10));
+}
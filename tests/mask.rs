@@ -0,0 +1,143 @@
+use nd_slice::{where_, NDBox, NDIntoIterator};
+
+mod util;
+use util::*;
+
+fn left() -> NDBox<i32, 2> {
+  NDBox::from([
+    [1, 5, 3],
+    [4, 2, 6],
+  ])
+}
+
+fn right() -> NDBox<i32, 2> {
+  NDBox::from([
+    [3, 5, 1],
+    [6, 2, 4],
+  ])
+}
+
+fn to_bool(mask: NDBox<i32, 2>) -> NDBox<bool, 2> {
+  mask.map(|value| value != 0)
+}
+
+#[test]
+fn test_comparisons() {
+  let left = left();
+  let left = left.as_slice();
+  let right = right();
+  let right = right.as_slice();
+  assert_eq!(left.eq_elem(right), to_bool(NDBox::from([
+    [0, 1, 0],
+    [0, 1, 0],
+  ])));
+  assert_eq!(left.ne_elem(right), to_bool(NDBox::from([
+    [1, 0, 1],
+    [1, 0, 1],
+  ])));
+  assert_eq!(left.lt_elem(right), to_bool(NDBox::from([
+    [1, 0, 0],
+    [1, 0, 0],
+  ])));
+  assert_eq!(left.le_elem(right), to_bool(NDBox::from([
+    [1, 1, 0],
+    [1, 1, 0],
+  ])));
+  assert_eq!(left.gt_elem(right), to_bool(NDBox::from([
+    [0, 0, 1],
+    [0, 0, 1],
+  ])));
+  assert_eq!(left.ge_elem(right), to_bool(NDBox::from([
+    [0, 1, 1],
+    [0, 1, 1],
+  ])));
+}
+
+#[test]
+fn test_compare_owned() {
+  assert_eq!(
+    NDBox::from([1.0, f64::NAN, 3.0]).lt_elem(NDBox::from([2.0, 2.0, 2.0])),
+    NDBox::from([true, false, false]),
+  );
+}
+
+#[test]
+fn test_compare_mismatched_lengths() {
+  let left = left();
+  let right = right();
+  assert_panics_with(
+    || drop(left.as_slice().lt_elem(right.as_slice().transpose())),
+    "Cannot operate on NDSlices with Len([2, 3]) and Len([3, 2])",
+  );
+}
+
+#[test]
+fn test_combine_masks() {
+  let left = left();
+  let left = left.as_slice();
+  let right = right();
+  let right = right.as_slice();
+  let mask = left.lt_elem(right) | left.eq_elem(right);
+  assert_eq!(mask, left.le_elem(right));
+  assert_eq!(!mask, left.gt_elem(right));
+  assert_eq!(left.le_elem(right) & left.ge_elem(right), left.eq_elem(right));
+}
+
+#[test]
+fn test_any_all() {
+  let left = left();
+  let left = left.as_slice();
+  let right = right();
+  let right = right.as_slice();
+  assert!(left.lt_elem(right).any());
+  assert!(!left.lt_elem(right).all());
+  assert!(left.le_elem(left).all());
+  assert!(!left.lt_elem(left).any());
+  let empty = NDBox::<bool, 2>::new_default([0, 3]);
+  assert!(!empty.any());
+  assert!(empty.all());
+  assert!(left.lt_elem(right).as_slice().any());
+}
+
+#[test]
+fn test_where() {
+  let left = left();
+  let left = left.as_slice();
+  let right = right();
+  let right = right.as_slice();
+  let mask = left.gt_elem(right);
+  assert_eq!(where_(mask.as_slice(), left, right), NDBox::from([
+    [3, 5, 3],
+    [6, 2, 6],
+  ]));
+  assert_panics_with(
+    || drop(where_(mask.as_slice(), left, left.transpose())),
+    "Cannot operate on NDSlices with Len([2, 3]), Len([2, 3]), and Len([3, 2])",
+  );
+}
+
+#[test]
+fn test_masked_select() {
+  let left = left();
+  let left = left.as_slice();
+  let mask = left.gt_elem(right().as_slice());
+  assert_eq!(left.masked_select(mask.as_slice()), NDBox::from([3, 6]));
+  assert_eq!(
+    left.transpose().masked_select(mask.as_slice().transpose()),
+    NDBox::from([3, 6]),
+  );
+  let none = NDBox::new_fill([2, 3], false);
+  assert_eq!(left.masked_select(none.as_slice()), NDBox::new_default([0]));
+}
+
+#[test]
+fn test_masked_fill() {
+  let mut left = left();
+  let right = right();
+  let mask = left.as_slice().lt_elem(right.as_slice());
+  left.as_mut().masked_fill(mask.as_slice(), 0);
+  assert_eq!(left, NDBox::from([
+    [0, 5, 3],
+    [0, 2, 6],
+  ]));
+}
@@ -0,0 +1,79 @@
+use nd_slice::NDBox;
+
+const NAN: f64 = f64::NAN;
+
+fn readings() -> NDBox<f64, 2> {
+  // Hours x sensors, with gaps
+  NDBox::from([
+    [1.0, NAN, 4.0],
+    [3.0, NAN, NAN],
+    [2.0, NAN, 6.0],
+    [NAN, NAN, 6.0],
+  ])
+}
+
+#[test]
+fn test_whole_slice_reductions() {
+  let readings = readings();
+  let readings = readings.as_slice();
+  assert_eq!(readings.nansum(), 22.0);
+  assert_eq!(readings.nanmean(), 22.0 / 6.0);
+  assert_eq!(readings.nanmin(), 1.0);
+  assert_eq!(readings.nanmax(), 6.0);
+  // The first maximum in row-major order is returned
+  assert_eq!(readings.nanargmax(), Some([2, 2]));
+  assert_eq!(readings.transpose().nanargmax(), Some([2, 2]));
+
+  let gaps = readings.extract::<1>(1);
+  assert_eq!(gaps.nansum(), 0.0);
+  assert!(gaps.nanmean().is_nan());
+  assert!(gaps.nanmin().is_nan());
+  assert!(gaps.nanmax().is_nan());
+  assert_eq!(gaps.nanargmax(), None);
+}
+
+#[test]
+fn test_axis_reductions() {
+  let readings = readings();
+  let readings = readings.as_slice();
+  let sums = readings.nansum_axis::<0>();
+  assert_eq!(sums, NDBox::from([6.0, 0.0, 16.0]));
+  assert_eq!(readings.nansum_axis::<1>(), NDBox::from([5.0, 3.0, 8.0, 6.0]));
+  let means = readings.nanmean_axis::<0>();
+  assert_eq!([means[[0]], means[[2]]], [2.0, 16.0 / 3.0]);
+  assert!(means[[1]].is_nan());
+  assert_eq!(readings.nanmin_axis::<1>(), NDBox::from([1.0, 3.0, 2.0, 6.0]));
+  assert_eq!(readings.nanmax_axis::<1>(), NDBox::from([4.0, 3.0, 6.0, 6.0]));
+  assert_eq!(readings.nanargmax_axis::<0>(), NDBox::from([Some(1), None, Some(2)]));
+  assert_eq!(readings.nanargmax_axis::<1>(), NDBox::from([Some(2), Some(0), Some(2), Some(2)]));
+}
+
+#[test]
+fn test_f32_and_infinities() {
+  let values = NDBox::<_, 1>::from([f32::NAN, f32::NEG_INFINITY, 2.5, f32::INFINITY]);
+  let values = values.as_slice();
+  assert_eq!(values.nanmin(), f32::NEG_INFINITY);
+  assert_eq!(values.nanmax(), f32::INFINITY);
+  assert_eq!(values.nanargmax(), Some([3]));
+  assert!(values.nansum().is_nan());
+  assert_eq!(values.is_nan(), NDBox::from([true, false, false, false]));
+  assert_eq!(values.is_finite(), NDBox::from([false, false, true, false]));
+}
+
+#[test]
+fn test_masks() {
+  let readings = readings();
+  let readings = readings.as_slice();
+  let missing = readings.is_nan();
+  assert_eq!(missing, NDBox::from([
+    [false, true, false],
+    [false, true, true],
+    [false, true, false],
+    [true, true, false],
+  ]));
+  assert_eq!(
+    readings.masked_select(readings.is_finite().as_slice()),
+    NDBox::from([1.0, 4.0, 3.0, 2.0, 6.0, 6.0]),
+  );
+  assert_eq!(missing.as_slice().count_where(|&missing| missing), 6);
+}
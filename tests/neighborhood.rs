@@ -0,0 +1,144 @@
+use nd_slice::{NDBox, NDIntoIterator, NDSlice, PadMode};
+
+fn row() -> NDBox<char, 1> {
+  NDBox::from(['a', 'b', 'c', 'd'])
+}
+
+fn neighborhoods(mode: PadMode<char>, radius: usize) -> NDBox<String, 1> {
+  row().as_slice().map_neighborhood([radius], mode, |neighborhood| {
+    neighborhood.into_iter().collect()
+  })
+}
+
+#[test]
+fn test_pad_modes() {
+  assert_eq!(
+    neighborhoods(PadMode::Constant('x'), 3),
+    NDBox::from(["xxxabcd", "xxabcdx", "xabcdxx", "abcdxxx"]).map(String::from),
+  );
+  assert_eq!(
+    neighborhoods(PadMode::Edge, 3),
+    NDBox::from(["aaaabcd", "aaabcdd", "aabcddd", "abcdddd"]).map(String::from),
+  );
+  assert_eq!(
+    neighborhoods(PadMode::Reflect, 3),
+    NDBox::from(["dcbabcd", "cbabcdc", "babcdcb", "abcdcba"]).map(String::from),
+  );
+  assert_eq!(
+    neighborhoods(PadMode::Symmetric, 3),
+    NDBox::from(["cbaabcd", "baabcdd", "aabcddc", "abcddcb"]).map(String::from),
+  );
+  assert_eq!(
+    neighborhoods(PadMode::Wrap, 3),
+    NDBox::from(["bcdabcd", "cdabcda", "dabcdab", "abcdabc"]).map(String::from),
+  );
+}
+
+#[test]
+fn test_large_radius() {
+  assert_eq!(
+    neighborhoods(PadMode::Reflect, 6)[[0]],
+    "abcdcbabcdcba",
+  );
+  assert_eq!(
+    neighborhoods(PadMode::Wrap, 5)[[0]],
+    "dabcdabcdab",
+  );
+  let single = NDBox::from(['z']);
+  let neighborhood = single.as_slice().map_neighborhood([2], PadMode::Reflect, |neighborhood| {
+    neighborhood.into_iter().collect::<String>()
+  });
+  assert_eq!(neighborhood, NDBox::from([String::from("zzzzz")]));
+}
+
+#[test]
+fn test_radius_0() {
+  let row = row();
+  assert_eq!(
+    row.as_slice().map_neighborhood([0], PadMode::Edge, |neighborhood| neighborhood[[0]]),
+    row,
+  );
+}
+
+#[test]
+fn test_empty() {
+  let empty = NDBox::<i32, 2>::new_default([0, 3]);
+  let result = empty.as_slice().map_neighborhood([1, 1], PadMode::Wrap, |_| -> i32 {
+    unreachable!()
+  });
+  assert_eq!(result, NDBox::new_default([0, 3]));
+}
+
+#[test]
+fn test_2_dimensions() {
+  let array = NDBox::from([
+    [1, 2, 3],
+    [4, 5, 6],
+  ]);
+  let sums = array.as_slice().map_neighborhood([1, 1], PadMode::Constant(0), |neighborhood| {
+    assert_eq!(neighborhood.len(), [3, 3]);
+    neighborhood.into_iter().sum::<i32>()
+  });
+  assert_eq!(sums, NDBox::from([
+    [12, 21, 16],
+    [12, 21, 16],
+  ]));
+  let vertical_differences =
+    array.as_slice().map_neighborhood([1, 0], PadMode::Edge, |neighborhood| {
+      neighborhood[[2, 0]] - neighborhood[[0, 0]]
+    });
+  assert_eq!(vertical_differences, NDBox::new_fill([2, 3], 3));
+}
+
+fn life_step(cells: NDSlice<bool, 2>) -> bool {
+  let alive = cells[[1, 1]];
+  let neighbors = cells.into_iter().filter(|&&cell| cell).count() - alive as usize;
+  neighbors == 3 || alive && neighbors == 2
+}
+
+fn glider() -> NDBox<bool, 2> {
+  NDBox::from([
+    [0, 1, 0, 0, 0],
+    [0, 0, 1, 0, 0],
+    [1, 1, 1, 0, 0],
+    [0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0],
+  ]).map(|cell| cell == 1)
+}
+
+#[test]
+fn test_game_of_life() {
+  let mut board = glider();
+  // After 4 generations, the glider moves 1 cell down and right
+  for _ in 0..4 {
+    board = board.as_slice().map_neighborhood([1, 1], PadMode::Constant(false), life_step);
+  }
+  assert_eq!(board, NDBox::from([
+    [0, 0, 0, 0, 0],
+    [0, 0, 1, 0, 0],
+    [0, 0, 0, 1, 0],
+    [0, 1, 1, 1, 0],
+    [0, 0, 0, 0, 0],
+  ]).map(|cell| cell == 1));
+}
+
+#[test]
+fn test_in_place() {
+  let mut board = glider();
+  // On a 5x5 torus, the glider returns to its starting position after 20 generations
+  for generation in 1..=20 {
+    board.as_mut().map_neighborhood_in_place([1, 1], PadMode::Wrap, life_step);
+    assert_eq!(board == glider(), generation == 20);
+  }
+}
+
+#[test]
+fn test_in_place_double_buffered() {
+  let mut values = NDBox::from([1.0, 2.0, 4.0, 8.0]);
+  let mut values_mut = values.as_mut();
+  values_mut.map_neighborhood_in_place([1], PadMode::Edge, |neighborhood| {
+    neighborhood.into_iter().sum::<f64>() / 3.0
+  });
+  // Each average is computed from the original values, not the updated ones
+  assert_eq!(values, NDBox::from([4.0 / 3.0, 7.0 / 3.0, 14.0 / 3.0, 20.0 / 3.0]));
+}
@@ -0,0 +1,71 @@
+use nd_slice::{NDBox, PadMode};
+
+mod util;
+use util::*;
+
+fn pad_row(widths: (usize, usize), mode: PadMode<char>) -> String {
+  let row = NDBox::from(['a', 'b', 'c', 'd']);
+  row.as_slice().pad([widths], mode).into_iter().collect()
+}
+
+#[test]
+fn test_modes() {
+  assert_eq!(pad_row((3, 3), PadMode::Constant('x')), "xxxabcdxxx");
+  assert_eq!(pad_row((3, 3), PadMode::Edge), "aaaabcdddd");
+  assert_eq!(pad_row((3, 3), PadMode::Reflect), "dcbabcdcba");
+  assert_eq!(pad_row((3, 3), PadMode::Symmetric), "cbaabcddcb");
+  assert_eq!(pad_row((3, 3), PadMode::Wrap), "bcdabcdabc");
+}
+
+#[test]
+fn test_asymmetric() {
+  assert_eq!(pad_row((0, 2), PadMode::Constant('x')), "abcdxx");
+  assert_eq!(pad_row((1, 0), PadMode::Edge), "aabcd");
+  assert_eq!(pad_row((0, 0), PadMode::Wrap), "abcd");
+}
+
+#[test]
+fn test_wide() {
+  assert_eq!(pad_row((7, 6), PadMode::Reflect), "babcdcbabcdcbabcd");
+  assert_eq!(pad_row((9, 9), PadMode::Symmetric), "aabcddcbaabcddcbaabcdd");
+  assert_eq!(pad_row((5, 5), PadMode::Wrap), "dabcdabcdabcda");
+}
+
+#[test]
+fn test_2_dimensions() {
+  let array = NDBox::from([
+    [1, 2, 3],
+    [4, 5, 6],
+  ]);
+  let array = array.as_slice();
+  assert_eq!(array.pad([(1, 0), (0, 2)], PadMode::Constant(0)), NDBox::from([
+    [0, 0, 0, 0, 0],
+    [1, 2, 3, 0, 0],
+    [4, 5, 6, 0, 0],
+  ]));
+  assert_eq!(array.pad([(1, 1), (1, 1)], PadMode::Edge), NDBox::from([
+    [1, 1, 2, 3, 3],
+    [1, 1, 2, 3, 3],
+    [4, 4, 5, 6, 6],
+    [4, 4, 5, 6, 6],
+  ]));
+  assert_eq!(array.transpose().pad([(1, 1), (0, 1)], PadMode::Wrap), NDBox::from([
+    [3, 6, 3],
+    [1, 4, 1],
+    [2, 5, 2],
+    [3, 6, 3],
+    [1, 4, 1],
+  ]));
+}
+
+#[test]
+fn test_empty() {
+  let empty = NDBox::<i32, 2>::new_default([0, 2]);
+  let empty = empty.as_slice();
+  assert_eq!(empty.pad([(1, 0), (1, 1)], PadMode::Constant(7)), NDBox::new_fill([1, 4], 7));
+  assert_eq!(empty.pad([(0, 0), (1, 1)], PadMode::Edge), NDBox::new_default([0, 4]));
+  assert_panics_with(
+    || drop(empty.pad([(1, 0), (0, 0)], PadMode::Reflect)),
+    "Cannot pad empty slice of Len([0, 2]) except with a constant",
+  );
+}
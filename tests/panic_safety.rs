@@ -0,0 +1,109 @@
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use nd_slice::{NDBox, NDIntoIterator};
+
+mod util;
+use util::*;
+
+/// Counts how many times it has been dropped
+struct DropCounter<'a> {
+  drops: &'a Cell<usize>,
+}
+
+impl Drop for DropCounter<'_> {
+  fn drop(&mut self) {
+    self.drops.set(self.drops.get() + 1);
+  }
+}
+
+/// Runs `f`, which is expected to panic
+fn catch_panic<F: FnOnce()>(f: F) {
+  panic::catch_unwind(AssertUnwindSafe(f)).expect_err("Did not panic");
+}
+
+#[test]
+fn test_new_with_panic() {
+  for panic_at in 0..12 {
+    let drops = Cell::new(0);
+    let created = Cell::new(0);
+    catch_panic(|| {
+      drop(NDBox::new_with([3, 4], |[index0, index1]| {
+        if index0 * 4 + index1 == panic_at {
+          panic!("Failed to initialize");
+        }
+        created.set(created.get() + 1);
+        DropCounter { drops: &drops }
+      }));
+    });
+    // Exactly the initialized prefix is dropped
+    assert_eq!(created.get(), panic_at);
+    assert_eq!(drops.get(), panic_at);
+  }
+}
+
+#[test]
+fn test_new_with_panic_message() {
+  assert_panics_with(
+    || drop(NDBox::new_with([2, 2], |index| {
+      assert!(index != [1, 0], "Cannot initialize {:?}", index);
+      index
+    })),
+    "Cannot initialize [1, 0]",
+  );
+}
+
+#[test]
+fn test_new_with_drops_all() {
+  let drops = Cell::new(0);
+  let counters = NDBox::new_with([2, 3, 4], |_| DropCounter { drops: &drops });
+  assert_eq!(drops.get(), 0);
+  drop(counters);
+  assert_eq!(drops.get(), 24);
+
+  let counters = NDBox::new_with([0, 3], |_| DropCounter { drops: &drops });
+  drop(counters);
+  assert_eq!(drops.get(), 24);
+}
+
+#[test]
+fn test_new_fill_clone_panic() {
+  /// Panics when cloned for the `limit`th time
+  struct CloneLimit<'a> {
+    clones: &'a Cell<usize>,
+    limit: usize,
+    _counter: DropCounter<'a>,
+  }
+
+  impl Clone for CloneLimit<'_> {
+    fn clone(&self) -> Self {
+      let clones = self.clones.get() + 1;
+      assert!(clones < self.limit, "Too many clones");
+      self.clones.set(clones);
+      let counter = DropCounter { drops: self._counter.drops };
+      Self { clones: self.clones, limit: self.limit, _counter: counter }
+    }
+  }
+
+  let drops = Cell::new(0);
+  let clones = Cell::new(0);
+  catch_panic(|| {
+    let value = CloneLimit { clones: &clones, limit: 5, _counter: DropCounter { drops: &drops } };
+    drop(NDBox::new_fill([10], value));
+  });
+  // The 4 clones and the original value are all dropped
+  assert_eq!(clones.get(), 4);
+  assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn test_map_panic() {
+  let values = NDBox::<_, 1>::from([0, 1, 2, 3, 4]);
+  let drops = Cell::new(0);
+  catch_panic(|| {
+    drop(values.as_slice().map(|&value| {
+      assert!(value < 3);
+      DropCounter { drops: &drops }
+    }));
+  });
+  assert_eq!(drops.get(), 3);
+}
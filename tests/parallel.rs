@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::thread;
+use nd_slice::{available_threads, matrix_product, par_matrix_product, NDBox, NDIntoIterator};
+
+mod util;
+use util::*;
+
+const THREAD_COUNTS: [usize; 5] = [1, 2, 3, 8, 100];
+
+fn values() -> NDBox<f64, 2> {
+  NDBox::new_with([13, 7], |[index0, index1]| (index0 * 7 + index1) as f64 / 3.0)
+}
+
+#[test]
+fn test_par_map() {
+  let values = values();
+  let values = values.as_slice();
+  for threads in THREAD_COUNTS {
+    assert_eq!(values.par_map(threads, |value| value * 2.0), values.map(|value| value * 2.0));
+    assert_eq!(
+      values.transpose().par_map(threads, |value| value.to_string()),
+      values.transpose().map(|value| value.to_string()),
+    );
+  }
+  let scalar = NDBox::from(1.5);
+  assert_eq!(scalar.as_slice().par_map(4, |value| value + 1.0), NDBox::from(2.5));
+  let empty = NDBox::<f64, 2>::new_default([0, 5]);
+  assert_eq!(empty.as_slice().par_map(4, |value| value + 1.0), NDBox::new_default([0, 5]));
+}
+
+#[test]
+fn test_par_map_uses_threads() {
+  let values = values();
+  let thread_ids = Mutex::new(HashSet::new());
+  values.as_slice().par_map(4, |_| thread_ids.lock().unwrap().insert(thread::current().id()));
+  assert_eq!(thread_ids.into_inner().unwrap().len(), 4);
+  assert!(available_threads() >= 1);
+}
+
+#[test]
+fn test_par_zip_map() {
+  let values = values();
+  let values = values.as_slice();
+  let squares = values.map(|value| value * value);
+  for threads in THREAD_COUNTS {
+    assert_eq!(
+      values.par_zip_map(squares.as_slice(), threads, |value, square| square - value),
+      values.zip_map(squares.as_slice(), |value, square| square - value),
+    );
+  }
+  assert_panics_with(
+    || drop(values.par_zip_map(values.transpose(), 2, |value1, value2| value1 + value2)),
+    "Cannot operate on NDSlices with Len([13, 7]) and Len([7, 13])",
+  );
+}
+
+#[test]
+fn test_par_map_inplace() {
+  for threads in THREAD_COUNTS {
+    let mut values = values();
+    let expected = values.as_slice().map(|value| value + 1.0);
+    values.as_mut().par_map_inplace(threads, |value| *value += 1.0);
+    assert_eq!(values, expected);
+  }
+
+  // Strided blocks only update their own elements
+  let mut values = NDBox::<_, 2>::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+  let mut middle = values.as_mut();
+  let mut middle = middle.extract_mut::<1>(1);
+  middle.par_map_inplace(3, |value| *value *= 10);
+  assert_eq!(values, NDBox::from([[1, 20, 3], [4, 50, 6], [7, 80, 9]]));
+}
+
+#[test]
+fn test_par_axis_reductions() {
+  let values = values();
+  let values = values.as_slice();
+  for threads in THREAD_COUNTS {
+    assert_eq!(values.par_sum_axis::<0>(threads), values.sum_axis::<0>());
+    assert_eq!(values.par_sum_axis::<1>(threads), values.sum_axis::<1>());
+    assert_eq!(values.par_mean_axis::<0>(threads), values.mean_axis::<0>());
+    assert_eq!(values.transpose().par_mean_axis::<0>(threads), values.mean_axis::<1>());
+  }
+
+  let cube = NDBox::new_with([4, 5, 6], |[index0, index1, index2]| {
+    index0 * 100 + index1 * 10 + index2
+  });
+  let cube = cube.as_slice();
+  assert!(cube.par_sum_axis::<0>(3) == cube.sum_axis::<0>());
+  assert!(cube.par_sum_axis::<1>(3) == cube.sum_axis::<1>());
+  assert!(cube.par_sum_axis::<2>(3) == cube.sum_axis::<2>());
+  let vector = NDBox::<_, 1>::from([1, 2, 3, 4]);
+  assert_eq!(vector.as_slice().par_sum_axis::<0>(4), NDBox::from(10));
+}
+
+#[test]
+fn test_par_matrix_product() {
+  let values = values();
+  let values = values.as_slice();
+  for threads in THREAD_COUNTS {
+    assert_eq!(
+      par_matrix_product::<_, _, f64>(values, values.transpose(), threads),
+      matrix_product::<_, _, f64>(values, values.transpose()),
+    );
+    assert_eq!(
+      par_matrix_product::<_, _, f64>(values.transpose(), values, threads),
+      matrix_product::<_, _, f64>(values.transpose(), values),
+    );
+  }
+  assert_panics_with(
+    || drop(par_matrix_product::<_, _, f64>(values, values, 2)),
+    "Cannot multiply matrices of Len([13, 7]) and Len([13, 7])",
+  );
+}
+
+#[test]
+fn test_panics() {
+  let values = values();
+  assert_panics_with(
+    || drop(values.as_slice().par_map(4, |&value| {
+      assert!(value < 20.0, "Value {} is too large", value);
+      value
+    })),
+    "Value 20 is too large",
+  );
+  assert_panics_with(
+    || drop(values.as_slice().par_map(0, |&value| value)),
+    "Cannot run on 0 threads",
+  );
+  // The number of threads is checked even if the slice is 0-dimensional
+  let scalar = NDBox::from(1.0);
+  assert_panics_with(
+    || drop(scalar.as_slice().par_map(0, |&value| value)),
+    "Cannot run on 0 threads",
+  );
+  assert_panics_with(
+    || scalar.clone().as_mut().par_map_inplace(0, |value| *value += 1.0),
+    "Cannot run on 0 threads",
+  );
+}
@@ -0,0 +1,103 @@
+use nd_slice::NDBox;
+
+mod util;
+use util::*;
+
+fn array() -> NDBox<i32, 2> {
+  NDBox::from([
+    [1, 2, 3, 4],
+    [5, 6, 7, 8],
+    [9, 10, 11, 12],
+  ])
+}
+
+#[test]
+fn test_roll() {
+  let array = array();
+  let array = array.as_slice();
+  assert_eq!(array.roll::<1>(1), NDBox::from([
+    [4, 1, 2, 3],
+    [8, 5, 6, 7],
+    [12, 9, 10, 11],
+  ]));
+  assert_eq!(array.roll::<0>(-1), NDBox::from([
+    [5, 6, 7, 8],
+    [9, 10, 11, 12],
+    [1, 2, 3, 4],
+  ]));
+  assert_eq!(array.roll::<1>(6), array.roll::<1>(2));
+  assert_eq!(array.roll::<1>(-6), array.roll::<1>(2));
+  assert_eq!(array.roll::<0>(3).as_slice(), array);
+  // Extreme shifts don't overflow
+  assert_eq!(array.roll::<1>(isize::MIN).as_slice(), array);
+  assert_eq!(array.roll::<0>(isize::MIN), array.roll::<0>(1));
+  assert_eq!(array.roll::<1>(isize::MAX), array.roll::<1>(-1));
+}
+
+#[test]
+fn test_roll_empty() {
+  let empty = NDBox::<i32, 2>::new_default([0, 3]);
+  assert_eq!(empty.as_slice().roll::<0>(2), empty);
+}
+
+#[test]
+fn test_tile() {
+  let array = NDBox::<_, 2>::from([
+    [1, 2],
+    [3, 4],
+  ]);
+  let array = array.as_slice();
+  assert_eq!(array.tile([2, 3]), NDBox::from([
+    [1, 2, 1, 2, 1, 2],
+    [3, 4, 3, 4, 3, 4],
+    [1, 2, 1, 2, 1, 2],
+    [3, 4, 3, 4, 3, 4],
+  ]));
+  assert_eq!(array.transpose().tile([1, 2]), NDBox::from([
+    [1, 3, 1, 3],
+    [2, 4, 2, 4],
+  ]));
+  assert_eq!(array.tile([0, 2]), NDBox::new_default([0, 4]));
+}
+
+#[test]
+fn test_repeat() {
+  let array = NDBox::<_, 2>::from([
+    [1, 2],
+    [3, 4],
+  ]);
+  let array = array.as_slice();
+  assert_eq!(array.repeat::<1>(3), NDBox::from([
+    [1, 1, 1, 2, 2, 2],
+    [3, 3, 3, 4, 4, 4],
+  ]));
+  assert_eq!(array.repeat::<0>(2), NDBox::from([
+    [1, 2],
+    [1, 2],
+    [3, 4],
+    [3, 4],
+  ]));
+  assert_eq!(array.repeat::<0>(0), NDBox::new_default([0, 2]));
+}
+
+#[test]
+fn test_repeat_view() {
+  let column = NDBox::<_, 2>::from([
+    [1],
+    [2],
+    [3],
+  ]);
+  let column = column.as_slice();
+  let repeated = column.repeat_view::<1>(4);
+  assert_eq!(repeated, NDBox::from([
+    [1, 1, 1, 1],
+    [2, 2, 2, 2],
+    [3, 3, 3, 3],
+  ]).as_slice());
+  assert_eq!(repeated, column.repeat::<1>(4).as_slice());
+  assert_eq!(repeated, column.tile([1, 4]).as_slice());
+  assert_panics_with(
+    || drop(column.repeat_view::<0>(2)),
+    "Cannot repeat dimension 0 of Len([3, 1]) without copying",
+  );
+}
@@ -0,0 +1,152 @@
+use nd_slice::{NDBox, NDIntoIterator};
+
+mod util;
+use util::*;
+
+fn series() -> NDBox<i32, 2> {
+  NDBox::from([
+    [3, 10],
+    [1, 20],
+    [4, 30],
+    [1, 25],
+    [5, 15],
+    [9, 5],
+  ])
+}
+
+#[test]
+fn test_sum() {
+  let series = series();
+  let series = series.as_slice();
+  assert_eq!(series.rolling::<0>(3).sum(), NDBox::from([
+    [8, 60],
+    [6, 75],
+    [10, 70],
+    [15, 45],
+  ]));
+  assert_eq!(series.rolling::<1>(2).sum(), NDBox::from([
+    [13],
+    [21],
+    [34],
+    [26],
+    [20],
+    [14],
+  ]));
+  assert_eq!(series.rolling::<0>(1).sum().as_slice(), series);
+}
+
+#[test]
+fn test_too_wide() {
+  let series = series();
+  let series = series.as_slice();
+  assert_eq!(series.rolling::<0>(6).sum(), NDBox::from([[23, 105]]));
+  assert_eq!(series.rolling::<0>(7).sum(), NDBox::new_default([0, 2]));
+  assert_eq!(series.rolling::<1>(3).max(), NDBox::new_default([6, 0]));
+}
+
+#[test]
+fn test_width_0() {
+  let series = series();
+  assert_panics_with(
+    || drop(series.as_slice().rolling::<0>(0)),
+    "Rolling window width is 0",
+  );
+}
+
+#[test]
+fn test_min_max() {
+  let series = series();
+  let series = series.as_slice();
+  assert_eq!(series.rolling::<0>(3).min(), NDBox::from([
+    [1, 10],
+    [1, 20],
+    [1, 15],
+    [1, 5],
+  ]));
+  assert_eq!(series.rolling::<0>(3).max(), NDBox::from([
+    [4, 30],
+    [4, 30],
+    [5, 30],
+    [9, 25],
+  ]));
+  assert_eq!(series.rolling::<0>(2).max(), NDBox::from([
+    [3, 20],
+    [4, 30],
+    [4, 30],
+    [5, 25],
+    [9, 15],
+  ]));
+}
+
+#[test]
+fn test_min_max_matches_apply() {
+  let values = NDBox::new_with([50], |[i]| (i * 37 % 11) as i32 - (i % 3) as i32);
+  let values = values.as_slice();
+  for width in 1..=8 {
+    let rolling = values.rolling::<0>(width);
+    assert_eq!(rolling.min(), rolling.apply(|window| *window.into_iter().min().unwrap()));
+    assert_eq!(rolling.max(), rolling.apply(|window| *window.into_iter().max().unwrap()));
+    assert_eq!(rolling.sum(), rolling.apply(|window| window.into_iter().sum()));
+  }
+}
+
+#[test]
+fn test_apply() {
+  let series = series();
+  let series = series.as_slice();
+  assert_eq!(
+    series.rolling::<0>(2).apply(|window| format!("{:?}", window)),
+    NDBox::from([
+      ["[3, 1]", "[10, 20]"],
+      ["[1, 4]", "[20, 30]"],
+      ["[4, 1]", "[30, 25]"],
+      ["[1, 5]", "[25, 15]"],
+      ["[5, 9]", "[15, 5]"],
+    ]).map(String::from),
+  );
+}
+
+#[test]
+fn test_mean() {
+  let temperatures = NDBox::<f64, 1>::from([72.0, 79.0, 76.0, 80.0, 77.0, 80.0, 78.0, 82.0, 81.0, 77.0]);
+  let means = temperatures.as_slice().rolling::<0>(7).mean();
+  let expected = [542.0 / 7.0, 552.0 / 7.0, 554.0 / 7.0, 555.0 / 7.0];
+  assert_eq!(means.as_slice().len(), [4]);
+  for (index, mean) in means.as_slice().iter() {
+    assert!((mean - expected[index[0]]).abs() < 1e-12);
+  }
+}
+
+#[test]
+fn test_std() {
+  let values = NDBox::<_, 1>::from([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, 100.0, 1.0, 2.0, 3.0]);
+  let values = values.as_slice();
+  for width in 1..=8 {
+    let rolling = values.rolling::<0>(width);
+    for ddof in 0..width {
+      let expected = rolling.apply(|window| {
+        let mean = window.into_iter().sum::<f64>() / width as f64;
+        let squared_deviations: f64 =
+          window.into_iter().map(|value| (value - mean) * (value - mean)).sum();
+        (squared_deviations / (width - ddof) as f64).sqrt()
+      });
+      for (index, std) in rolling.std(ddof).as_slice().iter() {
+        let expected = expected[index];
+        assert!((std - expected).abs() <= 1e-6 * expected.max(1.0), "{} != {}", std, expected);
+      }
+    }
+  }
+  assert_eq!(
+    NDBox::<_, 1>::from([1.0, 2.0, 3.0, 4.0]).as_slice().rolling::<0>(4).std(0),
+    NDBox::from([1.25f64.sqrt()]),
+  );
+}
+
+#[test]
+fn test_std_ddof_too_large() {
+  let values = NDBox::<_, 1>::from([1.0, 2.0, 3.0]);
+  assert_panics_with(
+    || drop(values.as_slice().rolling::<0>(2).std(2)),
+    "ddof 2 must be less than rolling window width 2",
+  );
+}
@@ -0,0 +1,58 @@
+use nd_slice::{NDBox, NDIntoIterator};
+
+fn temperatures() -> NDBox<i32, 2> {
+  // Days x cities
+  NDBox::from([
+    [72, 80, 79],
+    [79, 79, 75],
+    [76, 73, 83],
+    [80, 70, 72],
+  ])
+}
+
+#[test]
+fn test_argwhere() {
+  let temperatures = temperatures();
+  let temperatures = temperatures.as_slice();
+  assert_eq!(temperatures.argwhere(|&temperature| temperature >= 80), vec![[0, 1], [2, 2], [3, 0]]);
+  assert!(temperatures.argwhere(|&temperature| temperature > 100).is_empty());
+  assert_eq!(
+    temperatures.transpose().argwhere(|&temperature| temperature >= 80),
+    vec![[0, 3], [1, 0], [2, 2]],
+  );
+}
+
+#[test]
+fn test_nonzero() {
+  let values = NDBox::<_, 2>::from([
+    [0, 3, 0],
+    [-1, 0, 0],
+  ]);
+  assert_eq!(values.as_slice().nonzero(), vec![[0, 1], [1, 0]]);
+
+  let temperatures = temperatures();
+  let hot = temperatures.as_slice().gt_elem(NDBox::new_with([4, 3], |_| 78).as_slice());
+  assert_eq!(hot.as_slice().nonzero(), vec![[0, 1], [0, 2], [1, 0], [1, 1], [2, 2], [3, 0]]);
+  assert!(NDBox::<f64, 1>::new_default([0]).as_slice().nonzero().is_empty());
+}
+
+#[test]
+fn test_count_where() {
+  let temperatures = temperatures();
+  let temperatures = temperatures.as_slice();
+  assert_eq!(temperatures.count_where(|&temperature| temperature >= 79), 6);
+  assert_eq!(temperatures.count_where(|_| true), 12);
+  assert_eq!(temperatures.extract::<1>(2).count_where(|&temperature| temperature < 80), 3);
+}
+
+#[test]
+fn test_unique() {
+  let temperatures = temperatures();
+  let (values, counts) = temperatures.as_slice().unique();
+  assert_eq!(values, NDBox::from([70, 72, 73, 75, 76, 79, 80, 83]));
+  assert_eq!(counts, NDBox::from([1, 2, 1, 1, 1, 3, 2, 1]));
+
+  let (values, counts) = NDBox::<char, 1>::new_default([0]).as_slice().unique();
+  assert_eq!(values, NDBox::new_default([0]));
+  assert_eq!(counts, NDBox::new_default([0]));
+}
@@ -0,0 +1,78 @@
+use std::cell::Cell;
+use std::thread;
+use nd_slice::{NDBox, NDSlice, NDSliceMut};
+
+fn assert_send<T: Send>() {}
+
+fn assert_sync<T: Sync>() {}
+
+#[test]
+fn test_bounds() {
+  assert_send::<NDBox<f64, 2>>();
+  assert_sync::<NDBox<f64, 2>>();
+  assert_send::<NDSlice<f64, 2>>();
+  assert_sync::<NDSlice<f64, 2>>();
+  assert_send::<NDSliceMut<f64, 2>>();
+  assert_sync::<NDSliceMut<f64, 2>>();
+  // Like Box<[Cell<T>]> and &mut [Cell<T>], these can be sent but not shared
+  assert_send::<NDBox<Cell<i32>, 1>>();
+  assert_send::<NDSliceMut<Cell<i32>, 1>>();
+}
+
+#[test]
+fn test_send_box() {
+  let values = NDBox::<_, 2>::from([[1, 2], [3, 4]]);
+  let doubled = thread::spawn(move || {
+    let mut values = values;
+    for (_, value) in values.as_mut().iter_mut() {
+      *value *= 2;
+    }
+    values
+  }).join().unwrap();
+  assert_eq!(doubled, NDBox::from([[2, 4], [6, 8]]));
+}
+
+#[test]
+fn test_share_slice() {
+  let values = NDBox::<_, 2>::from([[1, 2, 3], [4, 5, 6]]);
+  let values = values.as_slice();
+  let row_sums: Vec<i32> = thread::scope(|scope| {
+    let handles: Vec<_> = (0..2)
+      .map(|row| scope.spawn(move || values.extract::<0>(row).into_iter().sum()))
+      .collect();
+    handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+  });
+  assert_eq!(row_sums, [6, 15]);
+
+  // A shared reference to a slice can also be used from multiple threads
+  let values = &values;
+  let total: i32 = thread::scope(|scope| {
+    let first = scope.spawn(|| values.extract::<1>(0).into_iter().sum::<i32>());
+    let rest = scope.spawn(|| *values.index([0, 1]) + *values.index([0, 2]));
+    first.join().unwrap() + rest.join().unwrap()
+  });
+  assert_eq!(total, 10);
+}
+
+#[test]
+fn test_send_slice_mut() {
+  let mut values = NDBox::new_with([4, 3], |[index0, index1]| index0 * 3 + index1);
+  let mut values_mut = values.as_mut();
+  thread::scope(|scope| {
+    for (_, mut chunk) in values_mut.chunks_mut([1, 3]) {
+      scope.spawn(move || {
+        for (_, value) in chunk.iter_mut() {
+          *value += 100;
+        }
+      });
+    }
+  });
+  assert_eq!(values, NDBox::new_with([4, 3], |[index0, index1]| index0 * 3 + index1 + 100));
+
+  let mut cells = NDBox::new_with([2], |_| Cell::new(0));
+  let mut cells_mut = cells.as_mut();
+  thread::scope(|scope| {
+    scope.spawn(move || cells_mut.index_mut([1]).set(5));
+  });
+  assert_eq!(cells.as_slice().index([1]).get(), 5);
+}
@@ -0,0 +1,175 @@
+use nd_slice::{Bounds, NDBox};
+
+mod util;
+use util::*;
+
+fn temperatures() -> NDBox<i32, 2> {
+  // Days x cities
+  NDBox::from([
+    [72, 80, 79],
+    [79, 79, 75],
+    [76, 73, 83],
+    [80, 70, 72],
+  ])
+}
+
+#[test]
+fn test_argsort_axis() {
+  let temperatures = temperatures();
+  let temperatures = temperatures.as_slice();
+  assert_eq!(temperatures.argsort_axis::<1>(), NDBox::from([
+    [0, 2, 1],
+    [2, 0, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+  ]));
+  assert_eq!(temperatures.argsort_axis::<0>(), NDBox::from([
+    [0, 3, 3],
+    [2, 2, 1],
+    [1, 1, 0],
+    [3, 0, 2],
+  ]));
+  // Ties keep their original order
+  assert_eq!(
+    NDBox::<_, 1>::from([3, 1, 3, 1]).as_slice().argsort_axis::<0>(),
+    NDBox::from([1, 3, 0, 2]),
+  );
+}
+
+#[test]
+fn test_argsort_axis_by() {
+  let values = NDBox::<_, 1>::from([2.5, -1.0, 0.0, 7.0]);
+  assert_eq!(
+    values.as_slice().argsort_axis_by::<0, _>(|a: &f64, b| b.total_cmp(a)),
+    NDBox::from([3, 0, 2, 1]),
+  );
+}
+
+#[test]
+fn test_top_k() {
+  let temperatures = temperatures();
+  let temperatures = temperatures.as_slice();
+  let hottest = temperatures.top_k::<0>(2);
+  assert_eq!(hottest, NDBox::from([
+    [3, 0, 2],
+    [1, 1, 0],
+  ]));
+  assert_eq!(temperatures.take_along_axis::<0>(hottest.as_slice()), NDBox::from([
+    [80, 80, 83],
+    [79, 79, 79],
+  ]));
+  // Ties are broken by lower index
+  assert_eq!(temperatures.top_k::<1>(1), NDBox::from([[1], [0], [2], [0]]));
+  assert_eq!(temperatures.top_k::<1>(3), NDBox::from([
+    [1, 2, 0],
+    [0, 1, 2],
+    [2, 0, 1],
+    [0, 2, 1],
+  ]));
+  assert_eq!(temperatures.top_k::<1>(0), NDBox::new_default([4, 0]));
+}
+
+#[test]
+fn test_top_k_too_large() {
+  let temperatures = temperatures();
+  assert_panics_with(
+    || drop(temperatures.as_slice().top_k::<1>(4)),
+    "Cannot take top 4 of dimension of len 3",
+  );
+}
+
+#[test]
+fn test_sort_axis_mut() {
+  let mut temperatures = temperatures();
+  temperatures.as_mut().sort_axis_mut::<1>();
+  assert_eq!(temperatures, NDBox::from([
+    [72, 79, 80],
+    [75, 79, 79],
+    [73, 76, 83],
+    [70, 72, 80],
+  ]));
+
+  // Lanes along dimension 0 are strided
+  let mut temperatures = self::temperatures();
+  temperatures.as_mut().sort_axis_mut::<0>();
+  assert_eq!(temperatures, NDBox::from([
+    [72, 70, 72],
+    [76, 73, 75],
+    [79, 79, 79],
+    [80, 80, 83],
+  ]));
+}
+
+#[test]
+fn test_sort_axis_by_mut() {
+  let mut values = NDBox::<_, 2>::from([
+    [3.0, f64::NAN, -1.0],
+    [0.5, 0.25, 2.0],
+  ]);
+  values.as_mut().sort_axis_by_mut::<1, _>(f64::total_cmp);
+  assert_eq!(values.as_slice().extract::<0>(1), NDBox::from([0.25, 0.5, 2.0]).as_slice());
+  let first_row = values.as_slice().extract::<0>(0);
+  assert_eq!([*first_row.index([0]), *first_row.index([1])], [-1.0, 3.0]);
+  assert!(first_row.index([2]).is_nan());
+}
+
+#[test]
+fn test_sort_sub_slice() {
+  let mut temperatures = temperatures();
+  let mut middle_days = temperatures.as_mut();
+  let mut middle_days = middle_days.slice_mut([Bounds::all().from(1).to(3), Bounds::all()]);
+  middle_days.sort_axis_by_mut::<1, _>(|a: &i32, b| b.cmp(a));
+  assert_eq!(temperatures, NDBox::from([
+    [72, 80, 79],
+    [79, 79, 75],
+    [83, 76, 73],
+    [80, 70, 72],
+  ]));
+}
+
+#[test]
+fn test_partition_axis_mut() {
+  let mut values = NDBox::<_, 1>::from([7, 2, 9, 4, 1, 8, 3]);
+  values.as_mut().partition_axis_mut::<0>(3);
+  let values = values.as_slice();
+  let pivot = *values.index([3]);
+  assert_eq!(pivot, 4);
+  for index in 0..3 {
+    assert!(*values.index([index]) <= pivot);
+  }
+  for index in 4..7 {
+    assert!(*values.index([index]) >= pivot);
+  }
+
+  let mut temperatures = temperatures();
+  temperatures.as_mut().partition_axis_by_mut::<0, _>(0, |a: &i32, b| b.cmp(a));
+  assert_eq!(temperatures.as_slice().extract::<0>(0), NDBox::from([80, 80, 83]).as_slice());
+}
+
+#[test]
+fn test_partition_out_of_bounds() {
+  let mut values = NDBox::<_, 1>::from([1, 2, 3]);
+  assert_panics_with(
+    move || values.as_mut().partition_axis_mut::<0>(3),
+    "Cannot partition at index 3 in dimension of len 3",
+  );
+}
+
+#[test]
+fn test_searchsorted() {
+  let values = NDBox::<_, 1>::from([1, 3, 3, 3, 7, 10]);
+  let values = values.as_slice();
+  assert_eq!(values.searchsorted(&0), 0);
+  assert_eq!(values.searchsorted(&3), 1);
+  assert_eq!(values.searchsorted_right(&3), 4);
+  assert_eq!(values.searchsorted(&5), 4);
+  assert_eq!(values.searchsorted_right(&5), 4);
+  assert_eq!(values.searchsorted(&10), 5);
+  assert_eq!(values.searchsorted_right(&11), 6);
+
+  // Works on strided slices too
+  let matrix = NDBox::<_, 2>::from([[0.5, 9.0], [1.5, 9.0], [2.5, 9.0]]);
+  let column = matrix.as_slice().extract::<1>(0);
+  assert_eq!(column.searchsorted(&2.0), 2);
+  assert_eq!(NDBox::<f64, 1>::new_default([0]).as_slice().searchsorted(&1.0), 0);
+}
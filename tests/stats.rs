@@ -0,0 +1,189 @@
+use nd_slice::{correlation, covariance, Interpolation, NDBox, NDIntoIterator};
+
+mod util;
+use util::*;
+
+fn assert_close<const N: usize>(actual: NDBox<f64, N>, expected: NDBox<f64, N>) {
+  assert_eq!(actual.as_slice().len(), expected.as_slice().len());
+  for (index, value) in actual.as_slice().iter() {
+    let expected_value = expected[index];
+    assert!(
+      (value - expected_value).abs() < 1e-9,
+      "{} != {} at {:?}", value, expected_value, index,
+    );
+  }
+}
+
+fn observations() -> NDBox<f64, 2> {
+  NDBox::from([
+    [2.0, 1.0],
+    [4.0, 3.0],
+    [4.0, 5.0],
+    [4.0, 7.0],
+    [5.0, 9.0],
+    [5.0, 11.0],
+    [7.0, 13.0],
+    [9.0, 15.0],
+  ])
+}
+
+#[test]
+fn test_sum_and_mean_axis() {
+  let observations = observations();
+  let observations = observations.as_slice();
+  assert_eq!(observations.sum_axis::<0>(), NDBox::from([40.0, 64.0]));
+  assert_eq!(observations.mean_axis::<0>(), NDBox::from([5.0, 8.0]));
+  assert_eq!(
+    observations.mean_axis::<1>(),
+    NDBox::from([1.5, 3.5, 4.5, 5.5, 7.0, 8.0, 10.0, 12.0]),
+  );
+  assert_eq!(
+    NDBox::<_, 2>::from([[1, 2, 3], [4, 5, 6]]).as_slice().sum_axis::<1>(),
+    NDBox::from([6, 15]),
+  );
+  assert_eq!(NDBox::<_, 1>::from([1, 2, 3]).as_slice().sum_axis::<0>(), NDBox::from(6));
+}
+
+#[test]
+fn test_var_and_std_axis() {
+  let observations = observations();
+  let observations = observations.as_slice();
+  assert_close(observations.var_axis::<0>(0), NDBox::from([4.0, 21.0]));
+  assert_close(observations.var_axis::<0>(1), NDBox::from([32.0 / 7.0, 24.0]));
+  assert_close(observations.std_axis::<0>(0), NDBox::from([2.0, 21f64.sqrt()]));
+  assert_close(
+    observations.transpose().std_axis::<1>(1),
+    NDBox::from([(32.0f64 / 7.0).sqrt(), 24f64.sqrt()]),
+  );
+}
+
+#[test]
+fn test_var_ddof_too_large() {
+  let observations = observations();
+  assert_panics_with(
+    || drop(observations.as_slice().var_axis::<1>(2)),
+    "ddof 2 must be less than dimension of len 2",
+  );
+}
+
+#[test]
+fn test_median_axis() {
+  let observations = observations();
+  let observations = observations.as_slice();
+  assert_eq!(observations.median_axis::<0>(), NDBox::from([4.5, 8.0]));
+  assert_eq!(
+    NDBox::<_, 1>::from([3.0f32, -1.0, 8.0]).as_slice().median_axis::<0>(),
+    NDBox::from(3.0),
+  );
+}
+
+#[test]
+fn test_quantile_axis() {
+  let observations = observations();
+  let observations = observations.as_slice();
+  // Position 1.75 in sorted order
+  let quartile = |interpolation| observations.quantile_axis::<0>(0.25, interpolation);
+  assert_eq!(quartile(Interpolation::Linear), NDBox::from([4.0, 4.5]));
+  assert_eq!(quartile(Interpolation::Lower), NDBox::from([4.0, 3.0]));
+  assert_eq!(quartile(Interpolation::Higher), NDBox::from([4.0, 5.0]));
+  assert_eq!(quartile(Interpolation::Nearest), NDBox::from([4.0, 5.0]));
+  assert_eq!(quartile(Interpolation::Midpoint), NDBox::from([4.0, 4.0]));
+  // Position 3.5 rounds to the even position 4
+  assert_eq!(
+    observations.quantile_axis::<0>(0.5, Interpolation::Nearest),
+    NDBox::from([5.0, 9.0]),
+  );
+  assert_eq!(observations.quantile_axis::<0>(0.0, Interpolation::Linear), NDBox::from([2.0, 1.0]));
+  assert_eq!(observations.quantile_axis::<0>(1.0, Interpolation::Linear), NDBox::from([9.0, 15.0]));
+  assert_eq!(
+    observations.quantile_axis::<1>(1.0, Interpolation::Higher),
+    NDBox::from([2.0, 4.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0]),
+  );
+}
+
+#[test]
+fn test_quantile_errors() {
+  let observations = observations();
+  assert_panics_with(
+    || drop(observations.as_slice().quantile_axis::<0>(1.5, Interpolation::Linear)),
+    "Quantile 1.5 is not between 0 and 1",
+  );
+  let empty = NDBox::<f64, 2>::new_default([0, 2]);
+  assert_panics_with(
+    || drop(empty.as_slice().median_axis::<0>()),
+    "Cannot compute quantile of empty dimension",
+  );
+  let with_nan = NDBox::<_, 1>::from([1.0, f64::NAN, 2.0]);
+  assert_panics_with(
+    || drop(with_nan.as_slice().median_axis::<0>()),
+    "Cannot compute quantile of NaN",
+  );
+}
+
+#[test]
+fn test_histogram() {
+  let observations = observations();
+  let observations = observations.as_slice();
+  let (counts, edges) = observations.histogram(4, (0.0, 16.0));
+  assert_eq!(counts, NDBox::from([3, 8, 3, 2]));
+  assert_eq!(edges, NDBox::from([0.0, 4.0, 8.0, 12.0, 16.0]));
+  // Values outside the range are ignored, and the last bin includes its right edge
+  let (counts, _) = observations.histogram(5, (4.0, 9.0));
+  assert_eq!(counts, NDBox::from([3, 3, 0, 2, 2]));
+
+  let values = NDBox::<_, 1>::from([0.3, 0.7, 0.1, 1.0, -0.1, f64::NAN]);
+  let (counts, edges) = values.as_slice().histogram(10, (0.0, 1.0));
+  assert_eq!(counts, NDBox::from([0, 1, 0, 1, 0, 0, 0, 1, 0, 1]));
+  assert_eq!(edges.as_slice().len(), [11]);
+}
+
+#[test]
+fn test_histogram_errors() {
+  let observations = observations();
+  assert_panics_with(
+    || drop(observations.as_slice().histogram(0, (0.0, 1.0))),
+    "Histogram must have at least 1 bin",
+  );
+  assert_panics_with(
+    || drop(observations.as_slice().histogram(3, (1.0, 1.0))),
+    "Histogram range (1.0, 1.0) is empty",
+  );
+}
+
+#[test]
+fn test_covariance() {
+  let observations = observations();
+  let observations = observations.as_slice();
+  assert_close(covariance(observations, 0), NDBox::from([
+    [4.0, 8.5],
+    [8.5, 21.0],
+  ]));
+  assert_close(covariance(observations, 1), NDBox::from([
+    [32.0 / 7.0, 68.0 / 7.0],
+    [68.0 / 7.0, 24.0],
+  ]));
+  assert_panics_with(
+    || drop(covariance(observations, 8)),
+    "ddof 8 must be less than number of observations 8",
+  );
+}
+
+#[test]
+fn test_correlation() {
+  let observations = observations();
+  let correlation_01 = 8.5 / 84f64.sqrt();
+  assert_close(correlation(observations.as_slice()), NDBox::from([
+    [1.0, correlation_01],
+    [correlation_01, 1.0],
+  ]));
+
+  let anticorrelated = NDBox::from([
+    [1.0, 3.0],
+    [2.0, 1.0],
+    [3.0, -1.0],
+  ]);
+  assert_eq!(correlation(anticorrelated.as_slice()), NDBox::from([
+    [1.0, -1.0],
+    [-1.0, 1.0],
+  ]));
+}
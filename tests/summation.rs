@@ -0,0 +1,81 @@
+use nd_slice::{matrix_product, matrix_product_compensated, NDBox};
+
+mod util;
+use util::*;
+
+#[test]
+fn test_sum_pairwise() {
+  let tenths = NDBox::new_with([1_000_000], |_| 0.1f32);
+  let tenths = tenths.as_slice();
+  let naive_sum: f32 = tenths.into_iter().sum();
+  let pairwise_sum = tenths.sum_pairwise();
+  assert!((naive_sum - 100_000.0).abs() > 100.0);
+  assert!((pairwise_sum - 100_000.0).abs() < 1.0, "{}", pairwise_sum);
+
+  let values = NDBox::<_, 2>::from([[1, 2, 3], [4, 5, 6]]);
+  assert_eq!(values.as_slice().sum_pairwise(), 21);
+  assert_eq!(NDBox::<i32, 2>::new_default([3, 0]).as_slice().sum_pairwise(), 0);
+}
+
+#[test]
+fn test_sum_kahan() {
+  let values = NDBox::<_, 1>::from([1.0, 1e100, 1.0, -1e100]);
+  let values = values.as_slice();
+  assert_eq!(values.into_iter().sum::<f64>(), 0.0);
+  assert_eq!(values.sum_kahan(), 2.0);
+
+  // Each small value is less than half the spacing between floats near 1,
+  // so naive summation rounds it away
+  let small_values = NDBox::new_with([1001], |[index]| if index == 0 { 1.0 } else { 1e-16 });
+  let small_values = small_values.as_slice();
+  assert_eq!(small_values.into_iter().sum::<f64>(), 1.0);
+  let sum = small_values.sum_kahan();
+  assert!((sum - 1.0000000000001).abs() < 1e-15, "{}", sum);
+  assert_eq!(NDBox::<f64, 1>::new_default([0]).as_slice().sum_kahan(), 0.0);
+}
+
+#[test]
+fn test_sum_independent_of_layout() {
+  // Values with many significant bits, so the order of additions matters
+  let len = [37, 101];
+  let values = NDBox::new_with(len, |[index0, index1]| {
+    1.0 / (index0 * len[1] + index1 + 1) as f64
+  });
+  let transposed = NDBox::new_with([len[1], len[0]], |[index1, index0]| {
+    *values.as_slice().index([index0, index1])
+  });
+  let values = values.as_slice();
+  let transposed_back = transposed.as_slice().transpose();
+  assert_eq!(values.sum_pairwise(), transposed_back.sum_pairwise());
+  assert_eq!(values.sum_kahan(), transposed_back.sum_kahan());
+  assert_eq!(values.sum_axis::<0>(), transposed_back.sum_axis::<0>());
+  assert_eq!(values.mean_axis::<1>(), transposed_back.mean_axis::<1>());
+  assert_eq!(values.sum_axis::<0>(), NDBox::new_with([len[1]], |[index1]| {
+    values.extract::<1>(index1).sum_pairwise()
+  }));
+}
+
+#[test]
+fn test_matrix_product_compensated() {
+  let matrix1 = NDBox::from([[1.0, 1e100, 1.0, -1e100]]);
+  let matrix2 = NDBox::from([[1.0], [1.0], [1.0], [1.0]]);
+  assert_eq!(
+    matrix_product::<_, _, f64>(matrix1.as_slice(), matrix2.as_slice()),
+    NDBox::from([[0.0]]),
+  );
+  assert_eq!(
+    matrix_product_compensated(matrix1.as_slice(), matrix2.as_slice()),
+    NDBox::from([[2.0]]),
+  );
+
+  let matrix = NDBox::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+  let matrix = matrix.as_slice();
+  assert_eq!(
+    matrix_product_compensated(matrix.transpose(), matrix),
+    NDBox::from([[35.0, 44.0], [44.0, 56.0]]),
+  );
+  assert_panics_with(
+    || drop(matrix_product_compensated(matrix, matrix)),
+    "Cannot multiply matrices of Len([3, 2]) and Len([3, 2])",
+  );
+}
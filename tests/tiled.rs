@@ -0,0 +1,227 @@
+use nd_slice::{Bounds, NDBox, NDError, NDTiled};
+
+mod util;
+use util::*;
+
+fn grid() -> NDBox<usize, 2> {
+  NDBox::new_with([5, 7], |[i, j]| i * 10 + j)
+}
+
+#[test]
+fn test_memory_order() {
+  let tiled = NDTiled::from_slice(grid().as_slice(), [2, 3]);
+  assert_eq!(tiled.len(), [5, 7]);
+  assert_eq!(tiled.tile_len(), [2, 3]);
+  let values: Vec<_> = tiled.iter_memory_order().map(|(_, &value)| value).collect();
+  // The first tile, then the second tile of the first row of tiles, etc.
+  assert_eq!(values[..6], [0, 1, 2, 10, 11, 12]);
+  assert_eq!(values[6..12], [3, 4, 5, 13, 14, 15]);
+  // The last tile in the first row of tiles is only 1 column wide
+  assert_eq!(values[12..14], [6, 16]);
+  // The last row of tiles is only 1 row tall
+  assert_eq!(values[values.len() - 4..], [43, 44, 45, 46]);
+  assert!(tiled.iter_memory_order().all(|([i, j], &value)| value == i * 10 + j));
+  assert_eq!(values.len(), 35);
+}
+
+#[test]
+fn test_iter() {
+  let grid = grid();
+  let mut tiled = NDTiled::from_slice(grid.as_slice(), [2, 3]);
+  // Like NDSlice::iter(), NDTiled::iter() is in row-major order
+  let values: Vec<_> = tiled.iter().collect();
+  let expected: Vec<_> = grid.as_slice().iter().collect();
+  assert_eq!(values, expected);
+  let slice_values: Vec<_> = tiled.as_slice().iter().collect();
+  assert_eq!(slice_values, expected);
+
+  let mut indices = vec![];
+  for (index, value) in tiled.iter_mut() {
+    indices.push(index);
+    *value += 100;
+  }
+  assert_eq!(indices, grid.as_slice().indices().collect::<Vec<_>>());
+  let mut memory_indices = vec![];
+  for (index, value) in tiled.iter_memory_order_mut() {
+    memory_indices.push(index);
+    *value -= 100;
+  }
+  assert_eq!(memory_indices[..4], [[0, 0], [0, 1], [0, 2], [1, 0]]);
+  assert_eq!(tiled.into_box(), grid);
+}
+
+#[test]
+fn test_new_with() {
+  let mut calls = vec![];
+  let tiled = NDTiled::new_with([3, 3], [2, 2], |index| {
+    calls.push(index);
+    index
+  });
+  assert_eq!(calls, vec![
+    [0, 0], [0, 1], [1, 0], [1, 1],
+    [0, 2], [1, 2],
+    [2, 0], [2, 1],
+    [2, 2],
+  ]);
+  assert_eq!(tiled[[2, 1]], [2, 1]);
+
+  let cube = NDTiled::new_with([3, 4, 5], [2, 3, 2], |index| index);
+  for i in 0..3 {
+    for j in 0..4 {
+      for k in 0..5 {
+        assert_eq!(cube[[i, j, k]], [i, j, k]);
+      }
+    }
+  }
+  assert_eq!(cube.iter().count(), 60);
+
+  let empty = NDTiled::new_with([3, 0], [2, 2], |_| 0);
+  assert_eq!(empty.iter().count(), 0);
+  let scalar = NDTiled::new_with([], [], |_| 1);
+  assert_eq!(scalar[[]], 1);
+}
+
+#[test]
+fn test_untiled_dimension() {
+  // A tile length of usize::MAX means each tile spans the whole dimension
+  let tiled = NDTiled::from_box(grid(), [2, usize::MAX]);
+  assert_eq!(tiled[[3, 6]], 36);
+  let values: Vec<_> = tiled.iter().map(|(_, &value)| value).take(9).collect();
+  assert_eq!(values, vec![0, 1, 2, 3, 4, 5, 6, 10, 11]);
+  assert_eq!(tiled.into_box(), grid());
+  let untiled = NDTiled::from_box(grid(), [usize::MAX, usize::MAX]);
+  assert_eq!(untiled.to_box(), grid());
+}
+
+#[test]
+fn test_zero_tile_len() {
+  assert_panics_with(|| {
+    NDTiled::new_with([2, 2], [1, 0], |_| 0);
+  }, "Tile length [1, 0] must be nonzero in every dimension");
+}
+
+#[test]
+fn test_get() {
+  let mut tiled = NDTiled::from_box(grid(), [4, 4]);
+  assert_eq!(tiled.get([4, 6]), Some(&46));
+  assert_eq!(tiled.get([5, 0]), None);
+  assert_eq!(tiled.get([0, 7]), None);
+  *tiled.get_mut([3, 5]).unwrap() = 0;
+  assert_eq!(tiled[[3, 5]], 0);
+  tiled[[3, 5]] = 35;
+  assert_eq!(tiled.get_mut([0, 9]), None);
+  assert_eq!(tiled.into_box(), grid());
+  assert_panics_with(|| {
+    let _ = NDTiled::from_box(grid(), [2, 2])[[1, 7]];
+  }, "Index([1, 7]) out of bounds for Len([5, 7])");
+}
+
+#[test]
+fn test_iter_mut() {
+  let mut tiled = NDTiled::from_box(grid(), [3, 2]);
+  for ([i, _], value) in tiled.iter_mut() {
+    *value += i;
+  }
+  assert_eq!(tiled.to_box(), NDBox::new_with([5, 7], |[i, j]| i * 11 + j));
+}
+
+#[test]
+fn test_conversions() {
+  let strings = NDBox::new_with([3, 4], |[i, j]| format!("{}{}", i, j));
+  let tiled = NDTiled::from_box(strings.clone(), [2, 2]);
+  assert_eq!(tiled[[2, 3]], "23");
+  assert_eq!(tiled.to_box(), strings);
+  assert_eq!(NDTiled::from_slice(strings.as_slice(), [3, 1]).into_box(), strings);
+  let transpose = NDTiled::from_slice(strings.as_slice().transpose(), [2, 2]);
+  assert_eq!(transpose[[3, 2]], "23");
+}
+
+#[test]
+fn test_slice() {
+  let grid = grid();
+  let tiled = NDTiled::from_slice(grid.as_slice(), [2, 3]);
+  let bounds = [Bounds::all().from(1).step(2), Bounds::all().from(2).to(6)];
+  let slice = tiled.slice(bounds);
+  assert_eq!(slice.len(), [2, 4]);
+  assert_eq!(slice.to_box(), grid.as_slice().slice(bounds).to_owned());
+  assert_eq!(slice[[1, 3]], 35);
+  assert_eq!(slice.get([2, 0]), None);
+  let values: Vec<_> = slice.iter().map(|(_, &value)| value).collect();
+  assert_eq!(values, vec![12, 13, 14, 15, 32, 33, 34, 35]);
+
+  let inner = slice.slice([Bounds::all().from(1), Bounds::all().step(3)]);
+  assert_eq!(inner.to_box(), NDBox::from([[32, 35]]));
+  assert_eq!(
+    slice.try_slice([Bounds::all(), Bounds::all().to(5)]).err(),
+    Some(NDError::RangeOutOfBounds { dimension: 1, range: 0..5, dimension_len: 4 }),
+  );
+  assert_eq!(tiled.as_slice().to_box(), grid);
+}
+
+#[test]
+fn test_slice_mut() {
+  let grid = grid();
+  let mut tiled = NDTiled::from_slice(grid.as_slice(), [2, 3]);
+  let bounds = [Bounds::all().from(1).step(2), Bounds::all().from(2).to(6)];
+  let mut slice = tiled.slice_mut(bounds);
+  assert_eq!(slice.len(), [2, 4]);
+  assert_eq!(slice.to_box(), grid.as_slice().slice(bounds).to_owned());
+  assert_eq!(slice.get([2, 0]), None);
+  assert_eq!(slice.get_mut([0, 4]), None);
+  for ([i, j], value) in slice.iter_mut() {
+    *value = 100 + i * 10 + j;
+  }
+  slice[[1, 3]] = 0;
+  let mut inner = slice.slice_mut([Bounds::all().from(1), Bounds::all().step(3)]);
+  assert_eq!(inner.to_box(), NDBox::from([[110, 0]]));
+  *inner.get_mut([0, 0]).unwrap() += 1;
+  assert_eq!(
+    slice.try_slice_mut([Bounds::all(), Bounds::all().to(5)]).err(),
+    Some(NDError::RangeOutOfBounds { dimension: 1, range: 0..5, dimension_len: 4 }),
+  );
+  assert_eq!(slice.as_slice()[[1, 0]], 111);
+
+  let expected = NDBox::new_with([5, 7], |[i, j]| match [i, j] {
+    [3, 5] => 0,
+    [3, 2] => 111,
+    [1 | 3, 2..=5] => 100 + (i - 1) / 2 * 10 + (j - 2),
+    _ => i * 10 + j,
+  });
+  assert_eq!(tiled.to_box(), expected);
+  assert_panics_with(|| {
+    let mut tiled = NDTiled::from_box(grid.clone(), [2, 2]);
+    tiled.as_mut()[[5, 0]] = 0;
+  }, "Index([5, 0]) out of bounds for Len([5, 7])");
+}
+
+#[test]
+fn test_traits() {
+  let tiled = NDTiled::from_box(NDBox::from([[1, 2, 3], [4, 5, 6]]), [1, 2]);
+  let mut clone = tiled.clone();
+  assert_eq!(clone.tile_len(), [1, 2]);
+  // Equality doesn't depend on the tile length
+  assert_eq!(clone, tiled);
+  assert_eq!(tiled, NDTiled::from_box(tiled.to_box(), [2, 2]));
+  assert_eq!(tiled.slice([Bounds::all(), Bounds::all().to(1)]), clone.as_slice().slice([
+    Bounds::all(),
+    Bounds::all().to(1),
+  ]));
+  clone[[1, 2]] = 0;
+  assert_ne!(clone, tiled);
+  let mut other = clone.clone();
+  assert_eq!(clone.as_mut(), other.as_mut());
+  assert_ne!(tiled.as_slice(), tiled.slice([Bounds::all().to(1), Bounds::all()]));
+
+  assert_eq!(
+    format!("{:?}", tiled),
+    "NDTiled { len: [2, 3], tile_len: [1, 2], values: [1, 2, 3, 4, 5, 6] }",
+  );
+  assert_eq!(
+    format!("{:?}", tiled.slice([Bounds::all().from(1), Bounds::all()])),
+    "NDTiledSlice { len: [1, 3], values: [4, 5, 6] }",
+  );
+  assert_eq!(
+    format!("{:?}", clone.as_mut()),
+    "NDTiledSlice { len: [2, 3], values: [1, 2, 3, 4, 5, 0] }",
+  );
+}
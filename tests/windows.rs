@@ -0,0 +1,128 @@
+use nd_slice::{NDBox, NDIntoIterator, NDSlice};
+
+mod util;
+use util::*;
+
+fn array() -> NDBox<i32, 2> {
+  NDBox::from([
+    [1, 2, 3, 4],
+    [5, 6, 7, 8],
+    [9, 10, 11, 12],
+  ])
+}
+
+fn to_boxes<'a>(slices: impl Iterator<Item = ([usize; 2], NDSlice<'a, i32, 2>)>)
+  -> Vec<([usize; 2], NDBox<i32, 2>)>
+{
+  slices.map(|(index, slice)| (index, slice.map(|&value| value))).collect()
+}
+
+#[test]
+fn test_windows() {
+  let array = array();
+  assert_eq!(to_boxes(array.as_slice().windows([2, 3])), [
+    ([0, 0], NDBox::from([
+      [1, 2, 3],
+      [5, 6, 7],
+    ])),
+    ([0, 1], NDBox::from([
+      [2, 3, 4],
+      [6, 7, 8],
+    ])),
+    ([1, 0], NDBox::from([
+      [5, 6, 7],
+      [9, 10, 11],
+    ])),
+    ([1, 1], NDBox::from([
+      [6, 7, 8],
+      [10, 11, 12],
+    ])),
+  ]);
+}
+
+#[test]
+fn test_windows_too_large() {
+  let array = array();
+  assert_eq!(array.as_slice().windows([4, 1]).count(), 0);
+  assert_eq!(array.as_slice().windows([3, 4]).count(), 1);
+}
+
+#[test]
+fn test_windows_empty() {
+  let array = array();
+  assert_panics_with(
+    || drop(array.as_slice().windows([1, 0])),
+    "Window size [1, 0] is empty",
+  );
+}
+
+#[test]
+fn test_chunks() {
+  let array = array();
+  assert_eq!(to_boxes(array.as_slice().chunks([2, 3])), [
+    ([0, 0], NDBox::from([
+      [1, 2, 3],
+      [5, 6, 7],
+    ])),
+    ([0, 1], NDBox::from([
+      [4],
+      [8],
+    ])),
+    ([1, 0], NDBox::from([
+      [9, 10, 11],
+    ])),
+    ([1, 1], NDBox::from([
+      [12],
+    ])),
+  ]);
+}
+
+#[test]
+fn test_exact_chunks() {
+  let array = array();
+  assert_eq!(to_boxes(array.as_slice().exact_chunks([1, 2])), [
+    ([0, 0], NDBox::from([[1, 2]])),
+    ([0, 1], NDBox::from([[3, 4]])),
+    ([1, 0], NDBox::from([[5, 6]])),
+    ([1, 1], NDBox::from([[7, 8]])),
+    ([2, 0], NDBox::from([[9, 10]])),
+    ([2, 1], NDBox::from([[11, 12]])),
+  ]);
+  assert_eq!(to_boxes(array.as_slice().exact_chunks([2, 3])), [
+    ([0, 0], NDBox::from([
+      [1, 2, 3],
+      [5, 6, 7],
+    ])),
+  ]);
+}
+
+#[test]
+fn test_chunks_transpose() {
+  let array = array();
+  assert_eq!(to_boxes(array.as_slice().transpose().exact_chunks([2, 2])), [
+    ([0, 0], NDBox::from([
+      [1, 5],
+      [2, 6],
+    ])),
+    ([1, 0], NDBox::from([
+      [3, 7],
+      [4, 8],
+    ])),
+  ]);
+}
+
+#[test]
+fn test_chunks_mut() {
+  let mut array = array();
+  let mut slice = array.as_mut();
+  for ([index0, index1], mut chunk) in slice.chunks_mut([2, 3]) {
+    for value in &mut chunk {
+      *value = (index0 * 10 + index1) as i32;
+    }
+  }
+  assert_eq!(array, NDBox::from([
+    [0, 0, 0, 1],
+    [0, 0, 0, 1],
+    [10, 10, 10, 11],
+  ]));
+}